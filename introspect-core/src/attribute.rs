@@ -0,0 +1,271 @@
+//! Rust attributes.
+//!
+//! Attributes (other than `#[doc]` and `#[introspect]`) are only captured when the
+//! `attributes` feature is enabled.
+
+#[cfg(feature = "parse")]
+use proc_macro2::TokenStream;
+#[cfg(feature = "parse")]
+use quote::quote;
+#[cfg(feature = "parse")]
+use quote::ToTokens;
+
+use crate::borrow::Slice;
+use crate::borrow::Str;
+#[cfg(feature = "parse")]
+use crate::r#type::normalize;
+#[cfg(feature = "parse")]
+use crate::r#type::option_tokens;
+#[cfg(feature = "parse")]
+use crate::r#type::slice_tokens;
+#[cfg(feature = "parse")]
+use crate::r#type::str_tokens;
+
+/// An attribute attached to a Rust entity or member, such as `#[deprecated]` or
+/// `#[serde(rename = "x")]`.
+#[derive(Clone, Debug)]
+pub struct Attribute {
+    /// The normalized source text of the path of the attribute.
+    path: Str,
+
+    /// The contents of the attribute.
+    meta: Meta,
+}
+
+/// The contents of an [`Attribute`].
+#[derive(Clone, Debug)]
+pub enum Meta {
+    /// An attribute that consists of only a path, such as `#[deprecated]`.
+    Path,
+
+    /// An attribute with a delimited list of tokens, such as `#[serde(rename = "x")]`.
+    List {
+        /// The normalized source text of the tokens within the delimiters.
+        tokens: Str,
+
+        /// The tokens parsed as a comma-separated list of nested attributes, if they
+        /// follow that syntax.
+        nested: Option<Slice<Attribute>>,
+    },
+
+    /// An attribute with a value, such as `#[deprecated = "reason"]`.
+    NameValue {
+        /// The normalized source text of the value.
+        value: Str,
+    },
+}
+
+impl Attribute {
+    /// Creates a new [`Attribute`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let attribute = core::Attribute::new(
+    ///     "deprecated".into(),
+    ///     core::attribute::Meta::Path
+    /// );
+    /// ```
+    pub const fn new(path: Str, meta: Meta) -> Self {
+        Self { path, meta }
+    }
+
+    /// Gets the normalized source text of the path of the [`Attribute`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let attribute: syn::Attribute = syn::parse_quote!(#[serde(rename = "x")]);
+    /// let attribute = core::Attribute::from(&attribute);
+    ///
+    /// assert_eq!(attribute.path(), "serde");
+    /// ```
+    pub fn path(&self) -> &str {
+        self.path.as_str()
+    }
+
+    /// Gets the contents of the [`Attribute`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    /// use core::attribute::Meta;
+    ///
+    /// let attribute: syn::Attribute = syn::parse_quote!(#[serde(rename = "x")]);
+    /// let attribute = core::Attribute::from(&attribute);
+    ///
+    /// match attribute.meta() {
+    ///     Meta::List { tokens, nested } => {
+    ///         assert_eq!(tokens, "rename = \"x\"");
+    ///
+    ///         let nested = nested.as_ref().unwrap();
+    ///         assert_eq!(nested[0].path(), "rename");
+    ///         assert!(matches!(nested[0].meta(), Meta::NameValue { value } if value == "\"x\""));
+    ///     }
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    pub fn meta(&self) -> &Meta {
+        &self.meta
+    }
+}
+
+#[cfg(feature = "parse")]
+impl ToTokens for Attribute {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let path = str_tokens(&self.path);
+        let meta = &self.meta;
+
+        tokens.extend(quote!(::introspect::attribute::Attribute::new(#path, #meta)))
+    }
+}
+
+#[cfg(feature = "parse")]
+impl ToTokens for Meta {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Meta::Path => quote!(::introspect::attribute::Meta::Path),
+            Meta::List { tokens, nested } => {
+                let tokens = str_tokens(tokens);
+                let nested =
+                    option_tokens(nested.as_ref().map(|nested| slice_tokens(nested.iter())));
+
+                quote!(::introspect::attribute::Meta::List { tokens: #tokens, nested: #nested })
+            }
+            Meta::NameValue { value } => {
+                let value = str_tokens(value);
+                quote!(::introspect::attribute::Meta::NameValue { value: #value })
+            }
+        })
+    }
+}
+
+#[cfg(feature = "parse")]
+impl From<&syn::Meta> for Attribute {
+    fn from(value: &syn::Meta) -> Self {
+        let meta = match value {
+            syn::Meta::Path(_) => Meta::Path,
+            syn::Meta::List(list) => Meta::List {
+                tokens: normalize(&list.tokens).into(),
+                nested: list
+                    .parse_args_with(
+                        syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated,
+                    )
+                    .ok()
+                    .map(|nested| nested.iter().map(Attribute::from).collect()),
+            },
+            syn::Meta::NameValue(name_value) => Meta::NameValue {
+                value: normalize(&name_value.value).into(),
+            },
+        };
+
+        Self {
+            path: normalize(value.path()).into(),
+            meta,
+        }
+    }
+}
+
+#[cfg(feature = "parse")]
+impl From<&syn::Attribute> for Attribute {
+    fn from(value: &syn::Attribute) -> Self {
+        Attribute::from(&value.meta)
+    }
+}
+
+/// Captures the attributes of an entity or member.
+///
+/// Documentation attributes and `#[introspect(..)]` helper attributes are never
+/// captured (as they are already reflected in the entity or member), and no
+/// attributes are captured unless the `attributes` feature is enabled.
+#[cfg(feature = "parse")]
+pub(crate) fn capture(attrs: &[syn::Attribute]) -> Vec<Attribute> {
+    if !cfg!(feature = "attributes") {
+        return Vec::new();
+    }
+
+    attrs
+        .iter()
+        .filter(|attr| !attr.path().is_ident("doc") && !crate::options::is_helper(attr))
+        .map(Attribute::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_captures_each_kind_of_meta() {
+        let attribute: syn::Attribute = syn::parse_quote!(#[deprecated]);
+        let attribute = Attribute::from(&attribute);
+        assert_eq!(attribute.path(), "deprecated");
+        assert!(matches!(attribute.meta(), Meta::Path));
+
+        let attribute: syn::Attribute = syn::parse_quote!(#[deprecated = "reason"]);
+        let attribute = Attribute::from(&attribute);
+        assert!(matches!(attribute.meta(), Meta::NameValue { value } if value == "\"reason\""));
+
+        let attribute: syn::Attribute = syn::parse_quote!(#[my_crate::custom(1 + 2)]);
+        let attribute = Attribute::from(&attribute);
+        assert_eq!(attribute.path(), "my_crate::custom");
+
+        match attribute.meta() {
+            Meta::List { tokens, nested } => {
+                assert_eq!(tokens, "1 + 2");
+                assert!(nested.is_none());
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn converts_to_tokens_correctly() {
+        let attribute: syn::Attribute = syn::parse_quote!(#[serde(skip)]);
+        let attribute = Attribute::from(&attribute);
+
+        assert_eq!(
+            attribute.to_token_stream().to_string(),
+            quote! {
+                ::introspect::attribute::Attribute::new(
+                    ::introspect::borrow::Str::Borrowed("serde"),
+                    ::introspect::attribute::Meta::List {
+                        tokens: ::introspect::borrow::Str::Borrowed("skip"),
+                        nested: ::core::option::Option::Some(::introspect::borrow::Slice::Borrowed(
+                            const {
+                                &[::introspect::attribute::Attribute::new(
+                                    ::introspect::borrow::Str::Borrowed("skip"),
+                                    ::introspect::attribute::Meta::Path
+                                )]
+                            }
+                        ))
+                    }
+                )
+            }
+            .to_string()
+        );
+    }
+
+    #[cfg(feature = "attributes")]
+    #[test]
+    fn capture_skips_documentation_and_helper_attributes() {
+        let item: syn::ItemStruct = syn::parse_quote! {
+            /// Documentation.
+            #[derive(Debug)]
+            #[deprecated]
+            #[introspect(hidden)]
+            struct Foo;
+        };
+
+        let attributes = capture(&item.attrs);
+
+        assert_eq!(attributes.len(), 2);
+        assert_eq!(attributes[0].path(), "derive");
+        assert_eq!(attributes[1].path(), "deprecated");
+    }
+}
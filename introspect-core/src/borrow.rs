@@ -0,0 +1,239 @@
+//! Strings and slices that are borrowed for the `'static` lifetime.
+//!
+//! The `Introspect` derive macro embeds all introspected data within the binary, so
+//! [`Str`] and [`Slice`] are always borrowed in that case. When the `alloc` feature
+//! is enabled, they may also be owned (e.g., when parsing or building entities).
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// A string that is either borrowed for the `'static` lifetime or owned.
+///
+/// The string is accessible via [`Deref`](core::ops::Deref).
+#[derive(Clone)]
+pub enum Str {
+    /// A string that is borrowed for the `'static` lifetime.
+    Borrowed(&'static str),
+
+    /// A string that is owned.
+    #[cfg(feature = "alloc")]
+    Owned(String),
+}
+
+/// A slice that is either borrowed for the `'static` lifetime or owned.
+///
+/// The slice is accessible via [`Deref`](core::ops::Deref).
+#[derive(Clone)]
+pub enum Slice<T: 'static> {
+    /// A slice that is borrowed for the `'static` lifetime.
+    Borrowed(&'static [T]),
+
+    /// A slice that is owned.
+    #[cfg(feature = "alloc")]
+    Owned(Vec<T>),
+}
+
+impl Str {
+    /// Gets the string as a string slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    /// use core::borrow::Str;
+    ///
+    /// assert_eq!(Str::Borrowed("Name").as_str(), "Name");
+    /// ```
+    pub fn as_str(&self) -> &str {
+        match self {
+            Str::Borrowed(value) => value,
+            #[cfg(feature = "alloc")]
+            Str::Owned(value) => value.as_str(),
+        }
+    }
+}
+
+impl<T: 'static> Slice<T> {
+    /// Gets the slice as a slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    /// use core::borrow::Slice;
+    ///
+    /// assert_eq!(Slice::Borrowed(&[1, 2]).as_slice(), [1, 2]);
+    /// ```
+    pub fn as_slice(&self) -> &[T] {
+        match self {
+            Slice::Borrowed(value) => value,
+            #[cfg(feature = "alloc")]
+            Slice::Owned(value) => value.as_slice(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Clone + 'static> Slice<T> {
+    /// Gets a mutable reference to the owned slice, cloning a borrowed slice first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    /// use core::borrow::Slice;
+    ///
+    /// let mut slice = Slice::Borrowed(&[1, 2]);
+    /// slice.to_mut().push(3);
+    ///
+    /// assert_eq!(slice.as_slice(), [1, 2, 3]);
+    /// ```
+    pub fn to_mut(&mut self) -> &mut Vec<T> {
+        if let Slice::Borrowed(value) = self {
+            *self = Slice::Owned(value.to_vec());
+        }
+
+        match self {
+            Slice::Owned(value) => value,
+            Slice::Borrowed(_) => unreachable!(),
+        }
+    }
+}
+
+impl core::ops::Deref for Str {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<T: 'static> core::ops::Deref for Slice<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl AsRef<str> for Str {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<T: 'static> AsRef<[T]> for Slice<T> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl Default for Str {
+    fn default() -> Self {
+        Str::Borrowed("")
+    }
+}
+
+impl<T: 'static> Default for Slice<T> {
+    fn default() -> Self {
+        Slice::Borrowed(&[])
+    }
+}
+
+impl core::fmt::Debug for Str {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<T: core::fmt::Debug + 'static> core::fmt::Debug for Slice<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(self.as_slice(), f)
+    }
+}
+
+impl PartialEq for Str {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for Str {}
+
+impl PartialEq<str> for Str {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Str {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<T: PartialEq + 'static> PartialEq for Slice<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq + 'static> Eq for Slice<T> {}
+
+impl From<&'static str> for Str {
+    fn from(value: &'static str) -> Self {
+        Str::Borrowed(value)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<String> for Str {
+    fn from(value: String) -> Self {
+        Str::Owned(value)
+    }
+}
+
+impl<T: 'static> From<&'static [T]> for Slice<T> {
+    fn from(value: &'static [T]) -> Self {
+        Slice::Borrowed(value)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: 'static> From<Vec<T>> for Slice<T> {
+    fn from(value: Vec<T>) -> Self {
+        Slice::Owned(value)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: 'static> FromIterator<T> for Slice<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Slice::Owned(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn borrowed_and_owned_values_are_equal() {
+        assert_eq!(Str::Borrowed("Name"), Str::from(String::from("Name")));
+        assert_eq!(Str::Borrowed("Name"), "Name");
+        assert_eq!(Slice::Borrowed(&[1, 2]), Slice::from(alloc::vec![1, 2]));
+    }
+
+    #[test]
+    fn to_mut_clones_a_borrowed_slice() {
+        static VALUES: [u8; 2] = [1, 2];
+
+        let mut slice = Slice::Borrowed(&VALUES);
+        slice.to_mut().push(3);
+
+        assert_eq!(slice.as_slice(), [1, 2, 3]);
+        assert_eq!(VALUES, [1, 2]);
+    }
+}
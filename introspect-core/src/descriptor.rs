@@ -0,0 +1,79 @@
+//! Entity descriptors.
+
+use crate::member::Members;
+use crate::Entity;
+
+/// The complete introspection of a Rust entity: the entity itself and its members.
+///
+/// The `Introspect` derive macro embeds an [`EntityDescriptor`] within each type as
+/// a `'static` constant, so it can be queried repeatedly without allocating. The
+/// members are borrowed from the entity, so each of them is only embedded once.
+#[derive(Debug)]
+pub struct EntityDescriptor {
+    /// The entity.
+    entity: Entity,
+}
+
+impl EntityDescriptor {
+    /// Creates a new [`EntityDescriptor`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let struct_ = core::r#struct::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .try_build()?;
+    ///
+    /// let descriptor = core::EntityDescriptor::new(core::Entity::Struct(struct_));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub const fn new(entity: Entity) -> Self {
+        Self { entity }
+    }
+
+    /// Gets the entity by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let struct_ = core::r#struct::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .try_build()?;
+    ///
+    /// let descriptor = core::EntityDescriptor::new(core::Entity::Struct(struct_));
+    ///
+    /// assert!(matches!(descriptor.entity(), core::Entity::Struct(_)));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn entity(&self) -> &Entity {
+        &self.entity
+    }
+
+    /// Gets an iterator over the members of the entity (see
+    /// [`Entity::members()`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let struct_ = core::r#struct::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .try_build()?;
+    ///
+    /// let descriptor = core::EntityDescriptor::new(core::Entity::Struct(struct_));
+    ///
+    /// assert_eq!(descriptor.members().len(), 0);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn members(&self) -> Members<'_> {
+        self.entity.members()
+    }
+}
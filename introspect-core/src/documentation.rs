@@ -0,0 +1,603 @@
+//! Structured documentation.
+//!
+//! Entities and members hold their documentation in two forms: normalized the way
+//! rustdoc does (the decoration of `/** .. */` block comments and the common
+//! leading whitespace are stripped) and raw (the text of each attribute exactly as
+//! written). Either form can be parsed into a [`Documentation`].
+//!
+//! # Documentation resolved by the derive macro
+//!
+//! The `Introspect` derive macro cannot evaluate macro calls (such as `#[doc =
+//! include_str!("../docs/config.md")]`) or the predicates of `#[cfg_attr(.., doc =
+//! ..)]` attributes. When the documentation includes either, the derive macro
+//! instead emits an expression that joins each part of the documentation when
+//! compiling the crate that derives `Introspect`, and that expression is passed as
+//! the documentation of the entity or member when it is constructed (e.g., via
+//! [`Struct::new()`](crate::Struct::new)).
+
+/// Documentation that is parsed into a summary, a body, and sections (such as
+/// `# Examples`, `# Errors`, and `# Panics`).
+///
+/// The documentation is parsed on demand and every part borrows from the original
+/// text, so parsing never allocates.
+///
+/// # Examples
+///
+/// ```
+/// use introspect_core as core;
+/// use core::Documentation;
+///
+/// let documentation = Documentation::new(
+///     "Parses a configuration.\n\nThe configuration is read from `config.toml`.\n\n# \
+///      Errors\n\nReturns an error if the file is missing.\n\n# \
+///      Examples\n\n```\nlet config = parse()?;\n```",
+/// );
+///
+/// assert_eq!(documentation.summary(), "Parses a configuration.");
+/// assert_eq!(
+///     documentation.body(),
+///     "The configuration is read from `config.toml`."
+/// );
+///
+/// let errors = documentation.section("Errors").unwrap();
+/// assert_eq!(errors.content(), "Returns an error if the file is missing.");
+///
+/// let examples = documentation.section("Examples").unwrap();
+/// let code = examples.code_blocks().next().unwrap();
+/// assert_eq!(code.code(), "let config = parse()?;");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Documentation<'a> {
+    /// The text of the documentation.
+    text: &'a str,
+}
+
+impl<'a> Documentation<'a> {
+    /// Creates a new [`Documentation`] from its text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let documentation = core::Documentation::new("Documentation.");
+    /// assert_eq!(documentation.as_str(), "Documentation.");
+    /// ```
+    pub const fn new(text: &'a str) -> Self {
+        Self { text }
+    }
+
+    /// Gets the text of the [`Documentation`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let documentation = core::Documentation::new("Documentation.");
+    /// assert_eq!(documentation.as_str(), "Documentation.");
+    /// ```
+    pub const fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    /// Gets the summary of the [`Documentation`], which is the first paragraph (as
+    /// shown by rustdoc in item listings).
+    ///
+    /// The summary is empty when the documentation begins with a section or a code
+    /// block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let documentation = core::Documentation::new("A summary\nthat wraps.\n\nThe body.");
+    /// assert_eq!(documentation.summary(), "A summary\nthat wraps.");
+    /// ```
+    pub fn summary(&self) -> &'a str {
+        let (start, end) = self.summary_range();
+        &self.text[start..end]
+    }
+
+    /// Gets the body of the [`Documentation`], which is everything between the
+    /// summary and the first section.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let documentation = core::Documentation::new(
+    ///     "The summary.\n\nThe body.\n\n```\nlet code = true;\n```\n\n# Examples\n\nNone.",
+    /// );
+    /// assert_eq!(documentation.body(), "The body.\n\n```\nlet code = true;\n```");
+    /// ```
+    pub fn body(&self) -> &'a str {
+        let (_, start) = self.summary_range();
+        let end = self.first_heading().unwrap_or(self.text.len());
+        trim(&self.text[start.min(end)..end])
+    }
+
+    /// Gets an iterator over the sections of the [`Documentation`].
+    ///
+    /// A section begins at each top-level heading (`# ..`) that is outside of a
+    /// code block and ends at the next one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let documentation = core::Documentation::new(
+    ///     "The summary.\n\n# Errors\n\nNever.\n\n# Panics\n\nAlways.",
+    /// );
+    ///
+    /// let names = documentation
+    ///     .sections()
+    ///     .map(|section| section.name())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(names, ["Errors", "Panics"]);
+    /// ```
+    pub fn sections(&self) -> Sections<'a> {
+        let start = self.first_heading().unwrap_or(self.text.len());
+
+        Sections {
+            text: &self.text[start..],
+        }
+    }
+
+    /// Gets a section of the [`Documentation`] by name (if it exists).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let documentation = core::Documentation::new("The summary.\n\n# Panics\n\nAlways.");
+    ///
+    /// assert_eq!(
+    ///     documentation.section("Panics").map(|section| section.content()),
+    ///     Some("Always.")
+    /// );
+    /// assert!(documentation.section("Errors").is_none());
+    /// ```
+    pub fn section(&self, name: &str) -> Option<Section<'a>> {
+        self.sections().find(|section| section.name() == name)
+    }
+
+    /// Gets an iterator over all of the fenced code blocks within the
+    /// [`Documentation`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let documentation = core::Documentation::new(
+    ///     "The summary.\n\n```text\nOutput.\n```\n\n# Examples\n\n```\n# use std;\nlet x = 1;\n```",
+    /// );
+    ///
+    /// let blocks = documentation.code_blocks().collect::<Vec<_>>();
+    /// assert_eq!(blocks.len(), 2);
+    /// assert_eq!(blocks[0].language(), Some("text"));
+    /// assert_eq!(blocks[1].code(), "# use std;\nlet x = 1;");
+    /// ```
+    pub fn code_blocks(&self) -> CodeBlocks<'a> {
+        CodeBlocks {
+            lines: Lines::new(self.text),
+        }
+    }
+
+    /// Gets the byte range of the summary.
+    fn summary_range(&self) -> (usize, usize) {
+        let mut range: Option<(usize, usize)> = None;
+
+        for line in Lines::new(self.text) {
+            match (line.kind, range) {
+                (Kind::Text, None) if line.is_blank() => continue,
+                (Kind::Text, Some(_)) if line.is_blank() => break,
+                (Kind::Text, None) => range = Some((line.start, line.end)),
+                (Kind::Text, Some((start, _))) => range = Some((start, line.end)),
+                _ => break,
+            }
+        }
+
+        let (start, end) = range.unwrap_or((0, 0));
+        (start, start + self.text[start..end].trim_end().len())
+    }
+
+    /// Gets the byte offset of the first section heading (if one exists).
+    fn first_heading(&self) -> Option<usize> {
+        Lines::new(self.text)
+            .find(|line| matches!(line.kind, Kind::Heading(_)))
+            .map(|line| line.start)
+    }
+}
+
+/// A section of [`Documentation`] (such as `# Examples`).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Section<'a> {
+    /// The name of the section.
+    name: &'a str,
+
+    /// The content of the section.
+    content: &'a str,
+}
+
+impl<'a> Section<'a> {
+    /// Gets the name of the [`Section`] (the text of its heading).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let documentation = core::Documentation::new("# Examples\n\nNone.");
+    /// let section = documentation.sections().next().unwrap();
+    ///
+    /// assert_eq!(section.name(), "Examples");
+    /// ```
+    pub const fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// Gets the content of the [`Section`] (everything after its heading).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let documentation = core::Documentation::new("# Examples\n\nNone.\n");
+    /// let section = documentation.sections().next().unwrap();
+    ///
+    /// assert_eq!(section.content(), "None.");
+    /// ```
+    pub const fn content(&self) -> &'a str {
+        self.content
+    }
+
+    /// Gets an iterator over the fenced code blocks within the [`Section`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let documentation = core::Documentation::new("# Examples\n\n```\nlet x = 1;\n```");
+    /// let section = documentation.sections().next().unwrap();
+    ///
+    /// assert_eq!(section.code_blocks().next().unwrap().code(), "let x = 1;");
+    /// ```
+    pub fn code_blocks(&self) -> CodeBlocks<'a> {
+        CodeBlocks {
+            lines: Lines::new(self.content),
+        }
+    }
+}
+
+/// A fenced code block within [`Documentation`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CodeBlock<'a> {
+    /// The info string that follows the opening fence.
+    info: &'a str,
+
+    /// The code within the fences.
+    code: &'a str,
+}
+
+impl<'a> CodeBlock<'a> {
+    /// Gets the info string of the [`CodeBlock`] (everything after the opening
+    /// fence, such as `rust,no_run`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let documentation = core::Documentation::new("```rust,no_run\nloop {}\n```");
+    /// let block = documentation.code_blocks().next().unwrap();
+    ///
+    /// assert_eq!(block.info(), "rust,no_run");
+    /// ```
+    pub const fn info(&self) -> &'a str {
+        self.info
+    }
+
+    /// Gets the language of the [`CodeBlock`] (the first word of its info string),
+    /// if one is specified.
+    ///
+    /// **Note:** rustdoc considers code blocks without a language to be Rust.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let documentation = core::Documentation::new("```rust,no_run\nloop {}\n```\n\n```\n```");
+    /// let mut blocks = documentation.code_blocks();
+    ///
+    /// assert_eq!(blocks.next().unwrap().language(), Some("rust"));
+    /// assert_eq!(blocks.next().unwrap().language(), None);
+    /// ```
+    pub fn language(&self) -> Option<&'a str> {
+        self.info
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .next()
+            .filter(|language| !language.is_empty())
+    }
+
+    /// Gets the code within the [`CodeBlock`] (without the fences).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let documentation = core::Documentation::new("```\nlet x = 1;\nlet y = 2;\n```");
+    /// let block = documentation.code_blocks().next().unwrap();
+    ///
+    /// assert_eq!(block.code(), "let x = 1;\nlet y = 2;");
+    /// ```
+    pub const fn code(&self) -> &'a str {
+        self.code
+    }
+}
+
+/// An iterator over the [`Section`]s of [`Documentation`].
+#[derive(Clone, Debug)]
+pub struct Sections<'a> {
+    /// The remaining text, which always begins with a heading (if not empty).
+    text: &'a str,
+}
+
+impl<'a> Iterator for Sections<'a> {
+    type Item = Section<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut lines = Lines::new(self.text);
+
+        let (name, start) = match lines.next()? {
+            Line {
+                kind: Kind::Heading(name),
+                end,
+                ..
+            } => (name, end),
+            // NOTE: the remaining text always begins with a heading.
+            _ => unreachable!(),
+        };
+
+        let end = lines
+            .find(|line| matches!(line.kind, Kind::Heading(_)))
+            .map(|line| line.start)
+            .unwrap_or(self.text.len());
+
+        let content = trim(&self.text[start..end]);
+        self.text = &self.text[end..];
+
+        Some(Section { name, content })
+    }
+}
+
+/// An iterator over the [`CodeBlock`]s of [`Documentation`] (or a [`Section`]).
+#[derive(Clone, Debug)]
+pub struct CodeBlocks<'a> {
+    /// The lines of the text.
+    lines: Lines<'a>,
+}
+
+impl<'a> Iterator for CodeBlocks<'a> {
+    type Item = CodeBlock<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (info, start) = self.lines.find_map(|line| match line.kind {
+            Kind::Fence {
+                info,
+                opening: true,
+            } => Some((info, line.end)),
+            _ => None,
+        })?;
+
+        let end = self
+            .lines
+            .find(|line| matches!(line.kind, Kind::Fence { opening: false, .. }))
+            .map(|line| line.start)
+            .unwrap_or(self.lines.text.len());
+
+        let code = &self.lines.text[start.min(end)..end];
+
+        Some(CodeBlock {
+            info,
+            code: code.strip_suffix('\n').unwrap_or(code),
+        })
+    }
+}
+
+/// The kind of a line of documentation.
+#[derive(Clone, Copy, Debug)]
+enum Kind<'a> {
+    /// A line of text.
+    Text,
+
+    /// A top-level heading (`# ..`) with the provided name.
+    Heading(&'a str),
+
+    /// An opening or closing code fence (` ``` ` or `~~~`).
+    Fence {
+        /// The info string (empty for closing fences).
+        info: &'a str,
+
+        /// Whether the fence opens a code block.
+        opening: bool,
+    },
+
+    /// A line within a fenced code block.
+    Code,
+}
+
+/// A line of documentation.
+#[derive(Clone, Copy, Debug)]
+struct Line<'a> {
+    /// The text of the line (without the line terminator).
+    text: &'a str,
+
+    /// The byte offset at which the line starts.
+    start: usize,
+
+    /// The byte offset at which the next line starts.
+    end: usize,
+
+    /// The kind of the line.
+    kind: Kind<'a>,
+}
+
+impl Line<'_> {
+    /// Returns whether the line is blank.
+    fn is_blank(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+}
+
+/// An iterator over the lines of documentation that tracks whether each line is
+/// within a fenced code block.
+#[derive(Clone, Debug)]
+struct Lines<'a> {
+    /// The text of the documentation.
+    text: &'a str,
+
+    /// The byte offset of the next line.
+    offset: usize,
+
+    /// The fence of the enclosing code block (if any).
+    fence: Option<&'a str>,
+}
+
+impl<'a> Lines<'a> {
+    /// Creates a new [`Lines`] iterator.
+    fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            offset: 0,
+            fence: None,
+        }
+    }
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = Line<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.text.len() {
+            return None;
+        }
+
+        let start = self.offset;
+        let rest = &self.text[start..];
+        let (text, end) = match rest.find('\n') {
+            Some(index) => (&rest[..index], start + index + 1),
+            None => (rest, self.text.len()),
+        };
+        let text = text.strip_suffix('\r').unwrap_or(text);
+        self.offset = end;
+
+        let trimmed = text.trim_start();
+        let marker = ['`', '~']
+            .into_iter()
+            .map(|c| &trimmed[..trimmed.len() - trimmed.trim_start_matches(c).len()])
+            .find(|marker| marker.len() >= 3);
+
+        let kind = match (self.fence, marker) {
+            (None, Some(marker)) => {
+                self.fence = Some(marker);
+                Kind::Fence {
+                    info: trimmed[marker.len()..].trim(),
+                    opening: true,
+                }
+            }
+            (Some(fence), Some(marker))
+                if marker.starts_with(fence) && trimmed[marker.len()..].trim().is_empty() =>
+            {
+                self.fence = None;
+                Kind::Fence {
+                    info: "",
+                    opening: false,
+                }
+            }
+            (Some(_), _) => Kind::Code,
+            (None, None) => match text.strip_prefix('#') {
+                Some(name) if name.is_empty() || name.starts_with([' ', '\t']) => {
+                    Kind::Heading(name.trim())
+                }
+                _ => Kind::Text,
+            },
+        };
+
+        Some(Line {
+            text,
+            start,
+            end,
+            kind,
+        })
+    }
+}
+
+/// Trims the leading blank lines and the trailing whitespace from the text.
+///
+/// Unlike [`str::trim()`], the indentation of the first non-blank line is kept.
+fn trim(text: &str) -> &str {
+    let text = text.trim_end();
+    let start = Lines::new(text)
+        .find(|line| !line.is_blank())
+        .map(|line| line.start)
+        .unwrap_or(text.len());
+
+    &text[start..]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn headings_within_code_blocks_are_ignored() {
+        let documentation = Documentation::new(
+            "The summary.\n\n# Examples\n\n```\n# use std::fmt;\n# fn main() {}\n```\n\n~~~~text\n```\n# Not a heading.\n~~~~\n\n#Not a heading either.\n\n# Panics\n\nNever.",
+        );
+
+        let sections = documentation.sections().collect::<Vec<_>>();
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[0].name(), "Examples");
+        assert_eq!(
+            sections[0].content(),
+            "```\n# use std::fmt;\n# fn main() {}\n```\n\n~~~~text\n```\n# Not a heading.\n~~~~\n\n#Not a heading either."
+        );
+        assert_eq!(sections[1].name(), "Panics");
+        assert_eq!(sections[1].content(), "Never.");
+
+        let blocks = sections[0].code_blocks().collect::<Vec<_>>();
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].code(), "# use std::fmt;\n# fn main() {}");
+        assert_eq!(blocks[1].language(), Some("text"));
+        assert_eq!(blocks[1].code(), "```\n# Not a heading.");
+    }
+
+    #[test]
+    fn documentation_without_a_summary_or_sections() {
+        let documentation = Documentation::new("# Examples\n\n```\nlet x = 1;");
+        assert_eq!(documentation.summary(), "");
+        assert_eq!(documentation.body(), "");
+        assert_eq!(
+            documentation.code_blocks().next().unwrap().code(),
+            "let x = 1;"
+        );
+
+        let documentation = Documentation::new("\n\nOnly a summary.\n");
+        assert_eq!(documentation.summary(), "Only a summary.");
+        assert_eq!(documentation.body(), "");
+        assert!(documentation.sections().next().is_none());
+
+        let documentation = Documentation::new("The summary.\n\n    let indented = true;\n");
+        assert_eq!(documentation.body(), "    let indented = true;");
+    }
+}
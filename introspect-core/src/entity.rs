@@ -1,33 +1,71 @@
+#[cfg(feature = "parse")]
 use proc_macro2::TokenStream;
+#[cfg(feature = "parse")]
+use quote::quote;
+#[cfg(feature = "parse")]
 use quote::ToTokens;
 
+use crate::member::Members;
 use crate::Enum;
 use crate::Struct;
+use crate::Union;
 
-/// A member of a Rust construct.
-#[derive(Debug)]
+/// A Rust construct that can be introspected: a struct, an enum, or a union.
+#[derive(Clone, Debug)]
 pub enum Entity {
     /// An enum.
     Enum(Enum),
 
     /// A struct.
     Struct(Struct),
+
+    /// A union.
+    Union(Union),
 }
 
-impl std::fmt::Display for Entity {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl Entity {
+    /// Gets an iterator over the members of the [`Entity`]: the fields of a struct
+    /// or union, or the variants of an enum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let variant = core::r#enum::variant::Builder::default()
+    ///                 .identifier("One")
+    ///                 .try_build()?;
+    ///
+    /// let enum_ = core::r#enum::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .variant(variant)
+    ///                 .try_build()?;
+    ///
+    /// let entity = core::Entity::Enum(enum_);
+    /// let mut members = entity.members();
+    ///
+    /// assert!(matches!(members.next(), Some(core::Member::Variant(variant)) if variant.identifier() == "One"));
+    /// assert!(members.next().is_none());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn members(&self) -> Members<'_> {
         match self {
-            Entity::Struct(struct_) => write!(f, "::introspect::Entity::Struct({})", struct_),
-            Entity::Enum(enum_) => write!(f, "::introspect::Entity::Enum({})", enum_),
+            Entity::Enum(enum_) => Members::variants(enum_.variants()),
+            Entity::Struct(struct_) => Members::fields(struct_.fields()),
+            Entity::Union(union_) => Members::fields(union_.fields()),
         }
     }
 }
 
+#[cfg(feature = "parse")]
 impl ToTokens for Entity {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        // SAFETY: this unwrap should never fail as we exhaustively test converting a
-        // [`Entity`] to a string that eventually parses to a token stream.
-        tokens.extend(self.to_string().parse::<TokenStream>().unwrap())
+        tokens.extend(match self {
+            Entity::Struct(struct_) => quote!(::introspect::Entity::Struct(#struct_)),
+            Entity::Enum(enum_) => quote!(::introspect::Entity::Enum(#enum_)),
+            Entity::Union(union_) => quote!(::introspect::Entity::Union(#union_)),
+        })
     }
 }
 
@@ -36,16 +74,112 @@ mod tests {
     use super::*;
 
     #[test]
-    fn an_enum_converts_to_string_correctly() {
-        let member = Entity::Enum(Enum::new("Name".into(), Some("Documentation.".into())));
+    fn an_enum_converts_to_tokens_correctly() {
+        let enum_ = crate::r#enum::Builder::default()
+            .identifier("Name")
+            .documentation("Documentation.")
+            .try_build()
+            .unwrap();
+
+        assert_eq!(
+            Entity::Enum(enum_).to_token_stream().to_string(),
+            quote! {
+                ::introspect::Entity::Enum(::introspect::Enum::new(::introspect::r#enum::Parts {
+                    identifier: ::introspect::borrow::Str::Borrowed("Name"),
+                    repr: ::core::option::Option::None,
+                    variants: ::introspect::borrow::Slice::Borrowed(const { &[] }),
+                    generics: ::introspect::generics::Generics::new(
+                        ::introspect::borrow::Slice::Borrowed(const { &[] }),
+                        ::introspect::borrow::Slice::Borrowed(const { &[] })
+                    ),
+                    visibility: ::introspect::visibility::Visibility::Private,
+                    attributes: ::introspect::borrow::Slice::Borrowed(const { &[] }),
+                    rename: ::core::option::Option::None,
+                    hidden: false,
+                    metadata: ::introspect::metadata::Metadata::new(
+                        ::introspect::borrow::Slice::Borrowed(const { &[] })
+                    ),
+                    location: ::core::option::Option::None,
+                    documentation: ::core::option::Option::Some(
+                        ::introspect::borrow::Str::Borrowed("Documentation.")
+                    ),
+                    documentation_raw: ::core::option::Option::None,
+                }))
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn a_struct_converts_to_tokens_correctly() {
+        let struct_ = crate::r#struct::Builder::default()
+            .identifier("Name")
+            .documentation("Documentation.")
+            .try_build()
+            .unwrap();
 
-        assert_eq!(member.to_string(), "::introspect::Entity::Enum(::introspect::Enum::new(r#\"Name\"#.into(), Some(r#\"Documentation.\"#.into())))");
+        assert_eq!(
+            Entity::Struct(struct_).to_token_stream().to_string(),
+            quote! {
+                ::introspect::Entity::Struct(::introspect::Struct::new(::introspect::r#struct::Parts {
+                    identifier: ::introspect::borrow::Str::Borrowed("Name"),
+                    kind: ::introspect::r#struct::StructKind::Unit,
+                    fields: ::introspect::borrow::Slice::Borrowed(const { &[] }),
+                    generics: ::introspect::generics::Generics::new(
+                        ::introspect::borrow::Slice::Borrowed(const { &[] }),
+                        ::introspect::borrow::Slice::Borrowed(const { &[] })
+                    ),
+                    visibility: ::introspect::visibility::Visibility::Private,
+                    attributes: ::introspect::borrow::Slice::Borrowed(const { &[] }),
+                    rename: ::core::option::Option::None,
+                    hidden: false,
+                    metadata: ::introspect::metadata::Metadata::new(
+                        ::introspect::borrow::Slice::Borrowed(const { &[] })
+                    ),
+                    location: ::core::option::Option::None,
+                    documentation: ::core::option::Option::Some(
+                        ::introspect::borrow::Str::Borrowed("Documentation.")
+                    ),
+                    documentation_raw: ::core::option::Option::None,
+                }))
+            }
+            .to_string()
+        );
     }
 
     #[test]
-    fn a_struct_converts_to_string_correctly() {
-        let member = Entity::Struct(Struct::new("Name".into(), Some("Documentation.".into())));
+    fn a_union_converts_to_tokens_correctly() {
+        let union_ = crate::union::Builder::default()
+            .identifier("Name")
+            .documentation("Documentation.")
+            .try_build()
+            .unwrap();
 
-        assert_eq!(member.to_string(), "::introspect::Entity::Struct(::introspect::Struct::new(r#\"Name\"#.into(), Some(r#\"Documentation.\"#.into())))");
+        assert_eq!(
+            Entity::Union(union_).to_token_stream().to_string(),
+            quote! {
+                ::introspect::Entity::Union(::introspect::Union::new(::introspect::union::Parts {
+                    identifier: ::introspect::borrow::Str::Borrowed("Name"),
+                    fields: ::introspect::borrow::Slice::Borrowed(const { &[] }),
+                    generics: ::introspect::generics::Generics::new(
+                        ::introspect::borrow::Slice::Borrowed(const { &[] }),
+                        ::introspect::borrow::Slice::Borrowed(const { &[] })
+                    ),
+                    visibility: ::introspect::visibility::Visibility::Private,
+                    attributes: ::introspect::borrow::Slice::Borrowed(const { &[] }),
+                    rename: ::core::option::Option::None,
+                    hidden: false,
+                    metadata: ::introspect::metadata::Metadata::new(
+                        ::introspect::borrow::Slice::Borrowed(const { &[] })
+                    ),
+                    location: ::core::option::Option::None,
+                    documentation: ::core::option::Option::Some(
+                        ::introspect::borrow::Str::Borrowed("Documentation.")
+                    ),
+                    documentation_raw: ::core::option::Option::None,
+                }))
+            }
+            .to_string()
+        );
     }
 }
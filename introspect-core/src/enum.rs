@@ -1,33 +1,78 @@
 //! Rust enums.
 
+#[cfg(feature = "alloc")]
 mod builder;
 pub mod variant;
 
+#[cfg(feature = "alloc")]
 pub use builder::Builder;
+
+#[cfg(feature = "parse")]
+use proc_macro2::TokenStream;
+#[cfg(feature = "parse")]
+use quote::quote;
+#[cfg(feature = "parse")]
+use quote::ToTokens;
+
+use crate::borrow::Slice;
+use crate::borrow::Str;
+#[cfg(feature = "parse")]
+use crate::parse::Resolved;
+#[cfg(feature = "parse")]
+use crate::r#type::option_tokens;
+#[cfg(feature = "parse")]
+use crate::r#type::slice_tokens;
+#[cfg(feature = "parse")]
+use crate::r#type::str_tokens;
+use crate::Attribute;
+use crate::Documentation;
+use crate::Generics;
+use crate::Location;
+use crate::Metadata;
+#[cfg(feature = "parse")]
+use crate::Options;
+use crate::Visibility;
 pub use variant::Variant;
+pub use variant::VariantKind;
 
 /// An error related to a [`Enum`].
+#[cfg(feature = "parse")]
 pub enum Error {
     /// Encountered an unsupported expression for a documentation attribute.
-    UnsupportedExpression(syn::Expr),
+    UnsupportedExpression(Box<syn::Expr>),
 
     /// Encountered an unsupported expression literal for a documentation attribute.
-    UnsupportedExpressionLiteral(syn::ExprLit),
+    UnsupportedExpressionLiteral(Box<syn::ExprLit>),
+
+    /// Encountered an invalid option within an `#[introspect(..)]` helper attribute.
+    InvalidOption(syn::Error),
+
+    /// Encountered an error with one of the variants of the enum.
+    Variant(variant::Error),
+
+    /// Encountered multiple errors (e.g., within several attributes), all of which
+    /// are reported at once.
+    Multiple(Vec<Error>),
 }
 
-impl std::fmt::Debug for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+#[cfg(feature = "parse")]
+impl core::fmt::Debug for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::UnsupportedExpression(_) => f.debug_tuple("UnsupportedExpression").finish(),
             Self::UnsupportedExpressionLiteral(_) => {
                 f.debug_tuple("UnsupportedExpressionLiteral").finish()
             }
+            Self::InvalidOption(err) => f.debug_tuple("InvalidOption").field(err).finish(),
+            Self::Variant(err) => f.debug_tuple("Variant").field(err).finish(),
+            Self::Multiple(errors) => f.debug_tuple("Multiple").field(errors).finish(),
         }
     }
 }
 
-impl std::fmt::Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+#[cfg(feature = "parse")]
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Error::UnsupportedExpression(_) => {
                 write!(f, "unsupported doc attribute expression")
@@ -35,41 +80,227 @@ impl std::fmt::Display for Error {
             Error::UnsupportedExpressionLiteral(_) => {
                 write!(f, "unsupported doc attribute literal")
             }
+            Error::InvalidOption(err) => write!(f, "invalid `introspect` option: {err}"),
+            Error::Variant(err) => write!(f, "variant error: {err}"),
+            Error::Multiple(errors) => {
+                for (i, err) in errors.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, "; ")?;
+                    }
+
+                    write!(f, "{err}")?;
+                }
+
+                Ok(())
+            }
         }
     }
 }
 
-impl std::error::Error for Error {}
+#[cfg(feature = "parse")]
+impl core::error::Error for Error {}
+
+#[cfg(feature = "parse")]
+impl From<Error> for syn::Error {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::UnsupportedExpression(expr) => crate::parse::unsupported_expression(&expr),
+            Error::UnsupportedExpressionLiteral(expr_lit) => {
+                crate::parse::unsupported_expression_literal(&expr_lit)
+            }
+            Error::InvalidOption(err) => err,
+            Error::Variant(err) => err.into(),
+            Error::Multiple(errors) => crate::parse::into_syn_error(errors),
+        }
+    }
+}
 
 /// A [`Result`](std::result::Result) with an [`Error`].
+#[cfg(feature = "parse")]
 pub type Result<T> = std::result::Result<T, Error>;
 
+/// The primitive integer types that can be used within a `#[repr(..)]` attribute.
+#[cfg(feature = "parse")]
+const INTEGER_TYPES: &[&str] = &[
+    "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
+];
+
+/// The parts of an [`Enum`], which are passed to [`Enum::new()`].
+///
+/// Unlike the builder, the parts can be constructed within a `const` context (as
+/// they are by the `Introspect` derive macro).
+#[derive(Clone, Debug)]
+pub struct Parts {
+    /// The identifier for the enum.
+    pub identifier: Str,
+
+    /// The integer type from the `#[repr(..)]` attribute of the enum, if it exists.
+    pub repr: Option<Str>,
+
+    /// The variants of the enum.
+    pub variants: Slice<Variant>,
+
+    /// The generics of the enum.
+    pub generics: Generics,
+
+    /// The visibility of the enum.
+    pub visibility: Visibility,
+
+    /// The attributes of the enum.
+    pub attributes: Slice<Attribute>,
+
+    /// The public name for the enum, if it was renamed.
+    pub rename: Option<Str>,
+
+    /// Whether or not the enum is hidden.
+    pub hidden: bool,
+
+    /// The user-defined metadata for the enum.
+    pub metadata: Metadata,
+
+    /// The location at which the enum is defined, if it is known.
+    pub location: Option<Location>,
+
+    /// The documentation for the enum, if it exists.
+    pub documentation: Option<Str>,
+
+    /// The raw documentation for the enum, if it exists.
+    pub documentation_raw: Option<Str>,
+}
+
 /// An enum.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Enum {
-    identifier: String,
-
-    documentation: Option<String>,
+    /// The parts of the enum.
+    parts: Parts,
 }
 
 impl Enum {
-    /// Creates a new [`Enum`].
+    /// Creates a new [`Enum`] from its [`Parts`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    /// use core::r#enum::Parts;
+    ///
+    /// let enum_ = core::Enum::new(Parts {
+    ///     identifier: "Name".into(),
+    ///     repr: Some("u8".into()),
+    ///     variants: Vec::new().into(),
+    ///     generics: core::Generics::default(),
+    ///     visibility: core::Visibility::Public,
+    ///     attributes: Vec::new().into(),
+    ///     rename: None,
+    ///     hidden: false,
+    ///     metadata: core::Metadata::default(),
+    ///     location: None,
+    ///     documentation: Some("Documentation.".into()),
+    ///     documentation_raw: Some(" Documentation.".into()),
+    /// });
+    ///
+    /// assert_eq!(enum_.identifier(), "Name");
+    /// ```
+    pub const fn new(parts: Parts) -> Self {
+        Self { parts }
+    }
+
+    /// Consumes `self` to produce an [`Enum`] defined at the provided location.
     ///
     /// # Examples
     ///
     /// ```
     /// use introspect_core as core;
     ///
-    /// let enum_ = core::Enum::new(
-    ///     String::from("Name"),
-    ///     Some(String::from("Documentation."))
+    /// let location = core::Location::new(
+    ///     "src/config.rs".into(),
+    ///     "my_crate::config".into(),
+    ///     42,
+    ///     1
     /// );
+    ///
+    /// let enum_ = core::r#enum::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .try_build()?
+    ///                 .with_location(location);
+    ///
+    /// assert_eq!(enum_.location().map(|location| location.file()), Some("src/config.rs"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn new(identifier: String, documentation: Option<String>) -> Self {
-        Self {
-            identifier,
-            documentation,
-        }
+    pub fn with_location(mut self, location: Location) -> Self {
+        self.parts.location = Some(location);
+        self
+    }
+
+    /// Consumes `self` to produce an [`Enum`] with the provided variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    /// use core::borrow::Slice;
+    ///
+    /// let variant = core::r#enum::variant::Builder::default()
+    ///                 .identifier("One")
+    ///                 .try_build()?;
+    ///
+    /// let enum_ = core::r#enum::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .try_build()?
+    ///                 .with_variants(Slice::from(vec![variant]));
+    ///
+    /// assert_eq!(enum_.variants().len(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_variants(mut self, variants: Slice<Variant>) -> Self {
+        self.parts.variants = variants;
+        self
+    }
+
+    /// Consumes `self` to produce an [`Enum`] with the provided documentation (or
+    /// without any documentation if `None` is provided).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let enum_ = core::r#enum::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .try_build()?
+    ///                 .with_documentation(Some("Documentation.".into()));
+    ///
+    /// assert_eq!(enum_.documentation(), Some("Documentation."));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_documentation(mut self, documentation: Option<Str>) -> Self {
+        self.parts.documentation = documentation;
+        self
+    }
+
+    /// Consumes `self` to produce an [`Enum`] with the provided raw
+    /// documentation (or without any raw documentation if `None` is provided).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let enum_ = core::r#enum::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .try_build()?
+    ///                 .with_documentation_raw(Some(" Documentation.".into()));
+    ///
+    /// assert_eq!(enum_.documentation_raw(), Some(" Documentation."));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_documentation_raw(mut self, documentation_raw: Option<Str>) -> Self {
+        self.parts.documentation_raw = documentation_raw;
+        self
     }
 
     /// Gets the identifier of the [`Enum`] by reference.
@@ -89,7 +320,222 @@ impl Enum {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn identifier(&self) -> &str {
-        self.identifier.as_str()
+        self.parts.identifier.as_str()
+    }
+
+    /// Gets the integer type from the `#[repr(..)]` attribute of the [`Enum`] by
+    /// reference, if one was specified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let enum_ = core::r#enum::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .repr("u8")
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(enum_.repr(), Some("u8"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn repr(&self) -> Option<&str> {
+        self.parts.repr.as_deref()
+    }
+
+    /// Gets the variants of the [`Enum`] by reference.
+    ///
+    /// Variants that are marked with `#[introspect(skip)]` are not included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let variant = core::r#enum::variant::Builder::default()
+    ///                 .identifier("One")
+    ///                 .try_build()?;
+    ///
+    /// let enum_ = core::r#enum::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .variant(variant)
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(enum_.variants().len(), 1);
+    /// assert_eq!(enum_.variants()[0].identifier(), "One");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn variants(&self) -> &[Variant] {
+        self.parts.variants.as_slice()
+    }
+
+    /// Gets the generics of the [`Enum`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let enum_ = core::r#enum::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .generics(core::Generics::from(&syn::parse_quote!(<T>)))
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(enum_.generics().params().len(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn generics(&self) -> &Generics {
+        &self.parts.generics
+    }
+
+    /// Gets the public name of the [`Enum`] by reference.
+    ///
+    /// This is the name provided via `#[introspect(rename = "...")]` or, if the enum
+    /// was not renamed, the identifier of the enum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let enum_ = core::r#enum::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .rename("Renamed")
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(enum_.identifier(), "Name");
+    /// assert_eq!(enum_.name(), "Renamed");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn name(&self) -> &str {
+        self.parts
+            .rename
+            .as_deref()
+            .unwrap_or(self.parts.identifier.as_str())
+    }
+
+    /// Gets whether the [`Enum`] is hidden (via `#[introspect(hidden)]`).
+    ///
+    /// Hidden enums are still introspected, but consumers are expected to leave them
+    /// out of user-facing output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let enum_ = core::r#enum::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .hidden(true)
+    ///                 .try_build()?;
+    ///
+    /// assert!(enum_.is_hidden());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn is_hidden(&self) -> bool {
+        self.parts.hidden
+    }
+
+    /// Gets the user-defined metadata of the [`Enum`] by reference.
+    ///
+    /// This is the metadata provided via `#[introspect(meta(..))]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let enum_ = core::r#enum::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .metadata("unit", "ms")
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(enum_.metadata().get("unit").and_then(|value| value.as_str()), Some("ms"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn metadata(&self) -> &Metadata {
+        &self.parts.metadata
+    }
+
+    /// Gets the location at which the [`Enum`] is defined by reference, if it is
+    /// known.
+    ///
+    /// The `Introspect` derive macro always provides the location.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let location = core::Location::new(
+    ///     "src/config.rs".into(),
+    ///     "my_crate::config".into(),
+    ///     42,
+    ///     1
+    /// );
+    ///
+    /// let enum_ = core::r#enum::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .location(location)
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(enum_.location().map(|location| location.line()), Some(42));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn location(&self) -> Option<&Location> {
+        self.parts.location.as_ref()
+    }
+
+    /// Gets the visibility of the [`Enum`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let enum_ = core::r#enum::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .visibility(core::Visibility::Crate)
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(enum_.visibility(), &core::Visibility::Crate);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn visibility(&self) -> &Visibility {
+        &self.parts.visibility
+    }
+
+    /// Gets the attributes of the [`Enum`] by reference.
+    ///
+    /// Attributes are only captured by the `Introspect` derive macro when the
+    /// `attributes` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let attribute: syn::Attribute = syn::parse_quote!(#[deprecated]);
+    ///
+    /// let enum_ = core::r#enum::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .attribute(core::Attribute::from(&attribute))
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(enum_.attributes()[0].path(), "deprecated");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn attributes(&self) -> &[Attribute] {
+        self.parts.attributes.as_slice()
     }
 
     /// Gets the documentation of the [`Enum`] by reference.
@@ -109,58 +555,276 @@ impl Enum {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn documentation(&self) -> Option<&str> {
-        self.documentation.as_deref()
+        self.parts.documentation.as_deref()
+    }
+
+    /// Gets the raw documentation of the [`Enum`] by reference.
+    ///
+    /// Unlike [`Enum::documentation()`], the text of each documentation attribute
+    /// is kept exactly as written: neither the common leading whitespace nor the
+    /// decoration of block comments (`/** .. */`) is stripped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let enum_ = core::r#enum::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .documentation_raw(" Documentation.")
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(enum_.documentation_raw(), Some(" Documentation."));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn documentation_raw(&self) -> Option<&str> {
+        self.parts.documentation_raw.as_deref()
+    }
+
+    /// Gets the documentation of the [`Enum`] parsed into a summary, a body, and
+    /// sections (see [`Documentation`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let enum_ = core::r#enum::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .documentation("The summary.\n\nThe body.\n\n# Panics\n\nNever.")
+    ///                 .try_build()?;
+    ///
+    /// let documentation = enum_.documentation_parsed().unwrap();
+    /// assert_eq!(documentation.summary(), "The summary.");
+    /// assert_eq!(documentation.body(), "The body.");
+    /// assert_eq!(documentation.section("Panics").unwrap().content(), "Never.");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn documentation_parsed(&self) -> Option<Documentation<'_>> {
+        self.documentation().map(Documentation::new)
     }
 }
 
-impl std::fmt::Display for Enum {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "::introspect::Enum::new(")?;
-        write!(f, "r#\"{}\"#.into(), ", self.identifier)?;
+#[cfg(feature = "parse")]
+impl Enum {
+    /// Converts the [`Enum`] into the tokens that construct it, replacing the
+    /// parts that are provided by `resolved` (see [`Resolved`]).
+    pub fn to_resolved_tokens(&self, resolved: &Resolved) -> TokenStream {
+        let identifier = str_tokens(&self.parts.identifier);
+        let repr = option_tokens(self.parts.repr.as_deref().map(str_tokens));
+        let variants = resolved.variants_tokens(&self.parts.variants);
+        let generics = &self.parts.generics;
+        let visibility = &self.parts.visibility;
+        let attributes = slice_tokens(self.parts.attributes.iter());
+        let rename = option_tokens(self.parts.rename.as_deref().map(str_tokens));
+        let hidden = self.parts.hidden;
+        let metadata = &self.parts.metadata;
+        let location = resolved.location_tokens(self.parts.location.as_ref());
+        let documentation = resolved.documentation_tokens(self.parts.documentation.as_deref());
+        let documentation_raw =
+            resolved.documentation_raw_tokens(self.parts.documentation_raw.as_deref());
 
-        match self.documentation.as_ref() {
-            Some(documentation) => write!(f, "Some(r#\"{}\"#.into())", documentation)?,
-            None => write!(f, "None")?,
-        };
+        quote! {
+            ::introspect::Enum::new(::introspect::r#enum::Parts {
+                identifier: #identifier,
+                repr: #repr,
+                variants: #variants,
+                generics: #generics,
+                visibility: #visibility,
+                attributes: #attributes,
+                rename: #rename,
+                hidden: #hidden,
+                metadata: #metadata,
+                location: #location,
+                documentation: #documentation,
+                documentation_raw: #documentation_raw,
+            })
+        }
+    }
+}
 
-        write!(f, ")")
+#[cfg(feature = "parse")]
+impl ToTokens for Enum {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(self.to_resolved_tokens(&Resolved::default()))
     }
 }
 
+#[cfg(feature = "parse")]
 impl TryFrom<&syn::ItemEnum> for Enum {
     type Error = Error;
 
     fn try_from(value: &syn::ItemEnum) -> Result<Self> {
-        let documentation = value
-            .attrs
+        let mut errors = Vec::new();
+
+        let options = match Options::try_from(value.attrs.as_slice()) {
+            Ok(options) => options,
+            Err(err) => {
+                errors.push(Error::InvalidOption(err));
+                Options::default()
+            }
+        };
+
+        if let Err(err) = options.deny_skip("enums") {
+            errors.push(Error::InvalidOption(err));
+        }
+
+        if let Err(err) = options.deny_reflect("enums") {
+            errors.push(Error::InvalidOption(err));
+        }
+
+        let (documentation, documentation_raw) = crate::parse::documentation(
+            &value.attrs,
+            &mut errors,
+            Error::UnsupportedExpression,
+            Error::UnsupportedExpressionLiteral,
+        );
+
+        let variants = value
+            .variants
             .iter()
-            .filter_map(|attr| match attr.meta.require_name_value() {
-                Ok(v) => Some(v),
-                Err(_) => None,
-            })
-            .filter_map(|enum_| {
-                enum_
-                    .path
-                    .get_ident()
-                    .map(|ident| (ident, enum_.value.clone()))
+            // NOTE: invalid options are reported when the variant itself is converted.
+            .filter(|variant| {
+                !Options::try_from(variant.attrs.as_slice()).is_ok_and(|options| options.skip())
             })
-            .filter(|(ident, _)| *ident == "doc")
-            .map(|(_, expr)| match expr {
-                syn::Expr::Lit(expr_lit) => match expr_lit.lit {
-                    syn::Lit::Str(lit_str) => Ok(lit_str.value().trim().to_string()),
-                    _ => Err(Error::UnsupportedExpressionLiteral(expr_lit)),
-                },
-                _ => Err(Error::UnsupportedExpression(expr)),
+            .filter_map(|variant| match Variant::try_from(variant) {
+                Ok(variant) => Some(variant),
+                Err(err) => {
+                    errors.push(Error::Variant(err));
+                    None
+                }
             })
-            .collect::<Result<Vec<String>>>()?
-            .join("\n");
-
-        Ok(Self {
-            identifier: value.ident.to_string(),
-            documentation: match documentation.is_empty() {
-                true => None,
-                false => Some(documentation),
-            },
-        })
+            .collect::<Vec<_>>();
+
+        let repr = value
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("repr"))
+            .find_map(|attr| {
+                let mut repr = None;
+
+                // NOTE: malformed `repr` attributes are reported by the compiler, so
+                // errors when parsing them are ignored here.
+                let _ = attr.parse_nested_meta(|meta| {
+                    if let Some(ident) = meta.path.get_ident() {
+                        if INTEGER_TYPES.iter().any(|ty| ident == ty) {
+                            repr = Some(ident.to_string().into());
+                        }
+                    }
+
+                    Ok(())
+                });
+
+                repr
+            });
+
+        if let Some(err) = crate::parse::combine(errors, Error::Multiple) {
+            return Err(err);
+        }
+
+        // NOTE: discriminants are introspected as an [`i128`], so the discriminants of
+        // `#[repr(u128)]` enums are not resolved, as they may exceed [`i128::MAX`].
+        let variants = match repr.as_deref() {
+            Some("u128") => variants
+                .into_iter()
+                .map(Variant::without_discriminant_value)
+                .collect(),
+            _ => variants,
+        };
+
+        Ok(Self::new(Parts {
+            identifier: value.ident.to_string().into(),
+            repr,
+            variants: variants.into(),
+            generics: Generics::from(&value.generics),
+            visibility: Visibility::from(&value.vis),
+            attributes: crate::attribute::capture(&value.attrs).into(),
+            rename: options.rename().map(|rename| rename.to_string().into()),
+            hidden: options.hidden(),
+            metadata: options.metadata().clone(),
+            location: None,
+            documentation: documentation.map(Into::into),
+            documentation_raw: documentation_raw.map(Into::into),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn try_from_captures_the_repr() {
+        let item: syn::ItemEnum = syn::parse_quote! {
+            #[derive(Clone, Copy)]
+            #[repr(C, u16)]
+            enum Foo {
+                A = 1,
+            }
+        };
+
+        assert_eq!(Enum::try_from(&item).unwrap().repr(), Some("u16"));
+
+        let item: syn::ItemEnum = syn::parse_quote! {
+            enum Foo {
+                A = 1,
+            }
+        };
+
+        assert_eq!(Enum::try_from(&item).unwrap().repr(), None);
+    }
+
+    #[test]
+    fn try_from_does_not_resolve_u128_discriminants() {
+        let item: syn::ItemEnum = syn::parse_quote! {
+            #[repr(u128)]
+            enum Foo {
+                A = 1,
+                B = u128::MAX,
+            }
+        };
+
+        let enum_ = Enum::try_from(&item).unwrap();
+        assert_eq!(enum_.repr(), Some("u128"));
+        assert!(enum_
+            .variants()
+            .iter()
+            .all(|variant| variant.discriminant_value().is_none()));
+        assert_eq!(enum_.variants()[0].discriminant(), Some("1"));
+    }
+
+    #[test]
+    fn try_from_resolves_the_variants() {
+        let item: syn::ItemEnum = syn::parse_quote! {
+            enum Foo {
+                A,
+                #[introspect(skip)]
+                B,
+                C(u8),
+            }
+        };
+
+        let enum_ = Enum::try_from(&item).unwrap();
+        let variants = enum_
+            .variants()
+            .iter()
+            .map(|variant| variant.identifier())
+            .collect::<Vec<_>>();
+        assert_eq!(variants, ["A", "C"]);
+        assert_eq!(enum_.variants()[1].fields().len(), 1);
+
+        let item: syn::ItemEnum = syn::parse_quote! {
+            enum Foo {
+                #[introspect(unknown)]
+                A,
+            }
+        };
+
+        assert!(matches!(
+            Enum::try_from(&item).unwrap_err(),
+            Error::Variant(_)
+        ));
     }
 }
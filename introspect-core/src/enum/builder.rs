@@ -1,4 +1,15 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::metadata::Value;
+use crate::r#enum::Parts;
+use crate::r#enum::Variant;
+use crate::Attribute;
 use crate::Enum;
+use crate::Generics;
+use crate::Location;
+use crate::Metadata;
+use crate::Visibility;
 
 /// An error related to a [`Builder`].
 #[derive(Debug)]
@@ -7,18 +18,18 @@ pub enum Error {
     MissingIdentifier,
 }
 
-impl std::fmt::Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Error::MissingIdentifier => write!(f, "missing identifier"),
         }
     }
 }
 
-impl std::error::Error for Error {}
+impl core::error::Error for Error {}
 
-/// A [`Result`](std::result::Result) with an [`Error`].
-pub type Result<T> = std::result::Result<T, Error>;
+/// A [`Result`](core::result::Result) with an [`Error`].
+pub type Result<T> = core::result::Result<T, Error>;
 
 /// A builder for a [`Enum`].
 #[derive(Debug, Default)]
@@ -26,8 +37,38 @@ pub struct Builder {
     /// An identifier for the enum.
     identifier: Option<String>,
 
+    /// The integer type from the `#[repr(..)]` attribute of the enum, if it exists.
+    repr: Option<String>,
+
+    /// The variants of the enum.
+    variants: Vec<Variant>,
+
+    /// The generics for the enum.
+    generics: Generics,
+
+    /// The visibility of the enum.
+    visibility: Visibility,
+
+    /// The attributes of the enum.
+    attributes: Vec<Attribute>,
+
+    /// The public name for the enum, if it was renamed.
+    rename: Option<String>,
+
+    /// Whether or not the enum is hidden.
+    hidden: bool,
+
+    /// The user-defined metadata for the enum.
+    metadata: Metadata,
+
+    /// The location at which the enum is defined, if it is known.
+    location: Option<Location>,
+
     /// The documentation for the enum, if it exists.
     documentation: Option<String>,
+
+    /// The raw documentation for the enum, if it exists.
+    documentation_raw: Option<String>,
 }
 
 impl Builder {
@@ -46,6 +87,156 @@ impl Builder {
         self
     }
 
+    /// Sets the `#[repr(..)]` integer type for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::r#enum::Builder::default()
+    ///                 .repr("u8");
+    /// ```
+    pub fn repr<S: Into<String>>(mut self, value: S) -> Self {
+        self.repr = Some(value.into());
+        self
+    }
+
+    /// Adds a variant to this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let variant = core::r#enum::variant::Builder::default()
+    ///                 .identifier("One")
+    ///                 .try_build()?;
+    ///
+    /// let builder = core::r#enum::Builder::default()
+    ///                 .variant(variant);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn variant(mut self, value: Variant) -> Self {
+        self.variants.push(value);
+        self
+    }
+
+    /// Sets the generics for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::r#enum::Builder::default()
+    ///                 .generics(core::Generics::from(&syn::parse_quote!(<T>)));
+    /// ```
+    pub fn generics(mut self, value: Generics) -> Self {
+        self.generics = value;
+        self
+    }
+
+    /// Sets the visibility for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::r#enum::Builder::default()
+    ///                 .visibility(core::Visibility::Public);
+    /// ```
+    pub fn visibility(mut self, value: Visibility) -> Self {
+        self.visibility = value;
+        self
+    }
+
+    /// Adds an attribute to this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let attribute: syn::Attribute = syn::parse_quote!(#[deprecated]);
+    ///
+    /// let builder = core::r#enum::Builder::default()
+    ///                 .attribute(core::Attribute::from(&attribute));
+    /// ```
+    pub fn attribute(mut self, value: Attribute) -> Self {
+        self.attributes.push(value);
+        self
+    }
+
+    /// Sets the public name for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::r#enum::Builder::default()
+    ///                 .rename("Renamed");
+    /// ```
+    pub fn rename<S: Into<String>>(mut self, value: S) -> Self {
+        self.rename = Some(value.into());
+        self
+    }
+
+    /// Sets whether the enum is hidden for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::r#enum::Builder::default()
+    ///                 .hidden(true);
+    /// ```
+    pub fn hidden(mut self, value: bool) -> Self {
+        self.hidden = value;
+        self
+    }
+
+    /// Adds a user-defined metadata entry to this [`Builder`].
+    ///
+    /// If the key already exists, its value is replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::r#enum::Builder::default()
+    ///                 .metadata("unit", "ms");
+    /// ```
+    pub fn metadata<K: Into<String>, V: Into<Value>>(mut self, key: K, value: V) -> Self {
+        self.metadata.insert(key, value);
+        self
+    }
+
+    /// Sets the location for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::r#enum::Builder::default()
+    ///                 .location(core::Location::new(
+    ///                     "src/config.rs".into(),
+    ///                     "my_crate::config".into(),
+    ///                     42,
+    ///                     1
+    ///                 ));
+    /// ```
+    pub fn location(mut self, value: Location) -> Self {
+        self.location = Some(value);
+        self
+    }
+
     /// Sets the documentation for this [`Builder`].
     ///
     /// # Examples
@@ -61,6 +252,21 @@ impl Builder {
         self
     }
 
+    /// Sets the raw documentation for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::r#enum::Builder::default()
+    ///                 .documentation_raw(" Documentation.");
+    /// ```
+    pub fn documentation_raw<S: Into<String>>(mut self, value: S) -> Self {
+        self.documentation_raw = Some(value.into());
+        self
+    }
+
     /// Consume `self` to produce an immutable [`Enum`].
     ///
     /// # Examples
@@ -81,9 +287,19 @@ impl Builder {
             None => return Err(Error::MissingIdentifier),
         };
 
-        Ok(Enum {
-            identifier,
-            documentation: self.documentation,
-        })
+        Ok(Enum::new(Parts {
+            identifier: identifier.into(),
+            repr: self.repr.map(Into::into),
+            variants: self.variants.into(),
+            generics: self.generics,
+            visibility: self.visibility,
+            attributes: self.attributes.into(),
+            rename: self.rename.map(Into::into),
+            hidden: self.hidden,
+            metadata: self.metadata,
+            location: self.location,
+            documentation: self.documentation.map(Into::into),
+            documentation_raw: self.documentation_raw.map(Into::into),
+        }))
     }
 }
@@ -1,31 +1,76 @@
 //! Rust enum variants.
 
+#[cfg(feature = "alloc")]
 mod builder;
 
+#[cfg(feature = "alloc")]
 pub use builder::Builder;
 
+#[cfg(feature = "parse")]
+use proc_macro2::TokenStream;
+#[cfg(feature = "parse")]
+use quote::quote;
+#[cfg(feature = "parse")]
+use quote::ToTokens;
+
+use crate::borrow::Slice;
+use crate::borrow::Str;
+#[cfg(feature = "parse")]
+use crate::parse::Resolved;
+#[cfg(feature = "parse")]
+use crate::r#struct::field;
+use crate::r#struct::Field;
+#[cfg(feature = "parse")]
+use crate::r#type::option_tokens;
+#[cfg(feature = "parse")]
+use crate::r#type::slice_tokens;
+#[cfg(feature = "parse")]
+use crate::r#type::str_tokens;
+use crate::Attribute;
+use crate::Documentation;
+use crate::Location;
+use crate::Metadata;
+#[cfg(feature = "parse")]
+use crate::Options;
+
 /// An error related to a [`Variant`].
+#[cfg(feature = "parse")]
 pub enum Error {
     /// Encountered an unsupported expression for a documentation attribute.
-    UnsupportedExpression(syn::Expr),
+    UnsupportedExpression(Box<syn::Expr>),
 
     /// Encountered an unsupported expression literal for a documentation attribute.
-    UnsupportedExpressionLiteral(syn::ExprLit),
+    UnsupportedExpressionLiteral(Box<syn::ExprLit>),
+
+    /// Encountered an invalid option within an `#[introspect(..)]` helper attribute.
+    InvalidOption(syn::Error),
+
+    /// Encountered an error with one of the fields of the variant.
+    Field(field::Error),
+
+    /// Encountered multiple errors (e.g., within several attributes), all of which
+    /// are reported at once.
+    Multiple(Vec<Error>),
 }
 
-impl std::fmt::Debug for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+#[cfg(feature = "parse")]
+impl core::fmt::Debug for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::UnsupportedExpression(_) => f.debug_tuple("UnsupportedExpression").finish(),
             Self::UnsupportedExpressionLiteral(_) => {
                 f.debug_tuple("UnsupportedExpressionLiteral").finish()
             }
+            Self::InvalidOption(err) => f.debug_tuple("InvalidOption").field(err).finish(),
+            Self::Field(err) => f.debug_tuple("Field").field(err).finish(),
+            Self::Multiple(errors) => f.debug_tuple("Multiple").field(errors).finish(),
         }
     }
 }
 
-impl std::fmt::Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+#[cfg(feature = "parse")]
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Error::UnsupportedExpression(_) => {
                 write!(f, "unsupported doc attribute expression")
@@ -33,43 +78,333 @@ impl std::fmt::Display for Error {
             Error::UnsupportedExpressionLiteral(_) => {
                 write!(f, "unsupported doc attribute literal")
             }
+            Error::InvalidOption(err) => write!(f, "invalid `introspect` option: {err}"),
+            Error::Field(err) => write!(f, "field error: {err}"),
+            Error::Multiple(errors) => {
+                for (i, err) in errors.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, "; ")?;
+                    }
+
+                    write!(f, "{err}")?;
+                }
+
+                Ok(())
+            }
         }
     }
 }
 
-impl std::error::Error for Error {}
+#[cfg(feature = "parse")]
+impl core::error::Error for Error {}
+
+#[cfg(feature = "parse")]
+impl From<Error> for syn::Error {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::UnsupportedExpression(expr) => crate::parse::unsupported_expression(&expr),
+            Error::UnsupportedExpressionLiteral(expr_lit) => {
+                crate::parse::unsupported_expression_literal(&expr_lit)
+            }
+            Error::InvalidOption(err) => err,
+            Error::Field(err) => err.into(),
+            Error::Multiple(errors) => crate::parse::into_syn_error(errors),
+        }
+    }
+}
 
 /// A [`Result`](std::result::Result) with an [`Error`].
+#[cfg(feature = "parse")]
 pub type Result<T> = std::result::Result<T, Error>;
 
-/// A Rust enum variant.
-#[derive(Debug)]
-pub struct Variant {
-    /// An identifier for the variant.
-    identifier: String,
+/// The shape of a [`Variant`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum VariantKind {
+    /// A variant without fields (e.g., `Foo::Bar`).
+    #[default]
+    Unit,
+
+    /// A variant with unnamed fields (e.g., `Foo::Bar(u32)`).
+    Tuple,
+
+    /// A variant with named fields (e.g., `Foo::Bar { x: i32 }`).
+    Named,
+}
+
+#[cfg(feature = "parse")]
+impl ToTokens for VariantKind {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            VariantKind::Unit => quote!(::introspect::r#enum::VariantKind::Unit),
+            VariantKind::Tuple => quote!(::introspect::r#enum::VariantKind::Tuple),
+            VariantKind::Named => quote!(::introspect::r#enum::VariantKind::Named),
+        })
+    }
+}
+
+#[cfg(feature = "parse")]
+impl Variant {
+    /// Converts the [`Variant`] into the tokens that construct it, replacing the
+    /// parts that are provided by `resolved` (see [`Resolved`]).
+    pub fn to_resolved_tokens(&self, resolved: &Resolved) -> TokenStream {
+        let identifier = str_tokens(&self.parts.identifier);
+        let kind = &self.parts.kind;
+        let fields = resolved.fields_tokens(&self.parts.fields);
+        let discriminant = option_tokens(self.parts.discriminant.as_deref().map(str_tokens));
+        let discriminant_value = resolved.discriminant_value_tokens(self.parts.discriminant_value);
+        let attributes = slice_tokens(self.parts.attributes.iter());
+        let rename = option_tokens(self.parts.rename.as_deref().map(str_tokens));
+        let hidden = self.parts.hidden;
+        let metadata = &self.parts.metadata;
+        let location = resolved.location_tokens(self.parts.location.as_ref());
+        let documentation = resolved.documentation_tokens(self.parts.documentation.as_deref());
+        let documentation_raw =
+            resolved.documentation_raw_tokens(self.parts.documentation_raw.as_deref());
+
+        quote! {
+            ::introspect::r#enum::Variant::new(::introspect::r#enum::variant::Parts {
+                identifier: #identifier,
+                kind: #kind,
+                fields: #fields,
+                discriminant: #discriminant,
+                discriminant_value: #discriminant_value,
+                attributes: #attributes,
+                rename: #rename,
+                hidden: #hidden,
+                metadata: #metadata,
+                location: #location,
+                documentation: #documentation,
+                documentation_raw: #documentation_raw,
+            })
+        }
+    }
+}
+
+#[cfg(feature = "parse")]
+impl ToTokens for Variant {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(self.to_resolved_tokens(&Resolved::default()))
+    }
+}
+
+#[cfg(feature = "parse")]
+impl From<&syn::Fields> for VariantKind {
+    fn from(value: &syn::Fields) -> Self {
+        match value {
+            syn::Fields::Unit => VariantKind::Unit,
+            syn::Fields::Unnamed(_) => VariantKind::Tuple,
+            syn::Fields::Named(_) => VariantKind::Named,
+        }
+    }
+}
+
+/// The parts of a [`Variant`], which are passed to [`Variant::new()`].
+///
+/// Unlike the builder, the parts can be constructed within a `const` context (as
+/// they are by the `Introspect` derive macro).
+#[derive(Clone, Debug)]
+pub struct Parts {
+    /// The identifier for the variant.
+    pub identifier: Str,
+
+    /// The shape of the variant.
+    pub kind: VariantKind,
+
+    /// The fields within the variant's payload.
+    pub fields: Slice<Field>,
+
+    /// The discriminant expression for the variant, if one was written.
+    pub discriminant: Option<Str>,
+
+    /// The resolved discriminant value for the variant, if it is known.
+    pub discriminant_value: Option<i128>,
+
+    /// The attributes of the variant.
+    pub attributes: Slice<Attribute>,
+
+    /// The public name for the variant, if it was renamed.
+    pub rename: Option<Str>,
+
+    /// Whether or not the variant is hidden.
+    pub hidden: bool,
+
+    /// The user-defined metadata for the variant.
+    pub metadata: Metadata,
+
+    /// The location at which the variant is defined, if it is known.
+    pub location: Option<Location>,
 
     /// The documentation for the variant, if it exists.
-    documentation: Option<String>,
+    pub documentation: Option<Str>,
+
+    /// The raw documentation for the variant, if it exists.
+    pub documentation_raw: Option<Str>,
+}
+
+/// A Rust enum variant.
+#[derive(Clone, Debug)]
+pub struct Variant {
+    /// The parts of the variant.
+    parts: Parts,
 }
 
 impl Variant {
-    /// Creates a new [`Variant`].
+    /// Creates a new [`Variant`] from its [`Parts`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    /// use core::r#enum::variant::Parts;
+    ///
+    /// let variant = core::r#enum::Variant::new(Parts {
+    ///     identifier: "Name".into(),
+    ///     kind: core::r#enum::VariantKind::Unit,
+    ///     fields: Vec::new().into(),
+    ///     discriminant: Some("3".into()),
+    ///     discriminant_value: Some(3),
+    ///     attributes: Vec::new().into(),
+    ///     rename: None,
+    ///     hidden: false,
+    ///     metadata: core::Metadata::default(),
+    ///     location: None,
+    ///     documentation: Some("Documentation.".into()),
+    ///     documentation_raw: Some(" Documentation.".into()),
+    /// });
+    ///
+    /// assert_eq!(variant.identifier(), "Name");
+    /// ```
+    pub const fn new(parts: Parts) -> Self {
+        Self { parts }
+    }
+
+    /// Consumes `self` to produce a [`Variant`] defined at the provided location.
     ///
     /// # Examples
     ///
     /// ```
     /// use introspect_core as core;
     ///
-    /// let variant = core::r#enum::Variant::new(
-    ///     String::from("Name"),
-    ///     Some(String::from("Documentation."))
+    /// let location = core::Location::new(
+    ///     "src/config.rs".into(),
+    ///     "my_crate::config".into(),
+    ///     42,
+    ///     1
     /// );
+    ///
+    /// let variant = core::r#enum::variant::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .try_build()?
+    ///                 .with_location(location);
+    ///
+    /// assert_eq!(variant.location().map(|location| location.file()), Some("src/config.rs"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn new(identifier: String, documentation: Option<String>) -> Self {
-        Self {
-            identifier,
-            documentation,
-        }
+    pub fn with_location(mut self, location: Location) -> Self {
+        self.parts.location = Some(location);
+        self
+    }
+
+    /// Consumes `self` to produce a [`Variant`] with the provided documentation (or
+    /// without any documentation if `None` is provided).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let variant = core::r#enum::variant::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .try_build()?
+    ///                 .with_documentation(Some("Documentation.".into()));
+    ///
+    /// assert_eq!(variant.documentation(), Some("Documentation."));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_documentation(mut self, documentation: Option<Str>) -> Self {
+        self.parts.documentation = documentation;
+        self
+    }
+
+    /// Consumes `self` to produce a [`Variant`] with the provided raw
+    /// documentation (or without any raw documentation if `None` is provided).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let variant = core::r#enum::variant::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .try_build()?
+    ///                 .with_documentation_raw(Some(" Documentation.".into()));
+    ///
+    /// assert_eq!(variant.documentation_raw(), Some(" Documentation."));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_documentation_raw(mut self, documentation_raw: Option<Str>) -> Self {
+        self.parts.documentation_raw = documentation_raw;
+        self
+    }
+
+    /// Consumes `self` to produce a [`Variant`] with the provided fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    /// use core::borrow::Slice;
+    ///
+    /// let field = core::r#struct::field::Builder::default()
+    ///                 .ty(core::TypeRef::from(&syn::parse_quote!(u32)))
+    ///                 .try_build()?;
+    ///
+    /// let variant = core::r#enum::variant::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .kind(core::r#enum::VariantKind::Tuple)
+    ///                 .try_build()?
+    ///                 .with_fields(Slice::from(vec![field]));
+    ///
+    /// assert_eq!(variant.fields().len(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_fields(mut self, fields: Slice<Field>) -> Self {
+        self.parts.fields = fields;
+        self
+    }
+
+    /// Consumes `self` to produce a [`Variant`] with the provided resolved
+    /// discriminant value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let variant = core::r#enum::variant::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .try_build()?
+    ///                 .with_discriminant_value(1);
+    ///
+    /// assert_eq!(variant.discriminant_value(), Some(1));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_discriminant_value(mut self, value: i128) -> Self {
+        self.parts.discriminant_value = Some(value);
+        self
+    }
+
+    /// Consumes `self` to produce a [`Variant`] without a resolved discriminant
+    /// value.
+    #[cfg(feature = "parse")]
+    pub(crate) fn without_discriminant_value(mut self) -> Self {
+        self.parts.discriminant_value = None;
+        self
     }
 
     /// Gets the identifier of the [`Variant`] by reference.
@@ -89,7 +424,227 @@ impl Variant {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn identifier(&self) -> &str {
-        self.identifier.as_str()
+        self.parts.identifier.as_str()
+    }
+
+    /// Gets the shape of the [`Variant`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let variant = core::r#enum::variant::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .kind(core::r#enum::VariantKind::Tuple)
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(variant.kind(), core::r#enum::VariantKind::Tuple);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn kind(&self) -> VariantKind {
+        self.parts.kind
+    }
+
+    /// Gets the fields within the payload of the [`Variant`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let field = core::r#struct::field::Builder::default()
+    ///                 .ty(core::TypeRef::from(&syn::parse_quote!(u32)))
+    ///                 .try_build()?;
+    ///
+    /// let variant = core::r#enum::variant::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .kind(core::r#enum::VariantKind::Tuple)
+    ///                 .field(field)
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(variant.fields().len(), 1);
+    /// assert_eq!(variant.fields()[0].ty().text(), "u32");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn fields(&self) -> &[Field] {
+        self.parts.fields.as_slice()
+    }
+
+    /// Gets the discriminant expression of the [`Variant`] by reference, if one was
+    /// written (e.g., `3` for `A = 3`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let variant = core::r#enum::variant::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .discriminant("1 << 2")
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(variant.discriminant(), Some("1 << 2"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn discriminant(&self) -> Option<&str> {
+        self.parts.discriminant.as_deref()
+    }
+
+    /// Gets the resolved discriminant value of the [`Variant`], if it is known.
+    ///
+    /// The value is known when the discriminant is written as an integer literal or
+    /// when the variant belongs to a fieldless enum that derives `Introspect`.
+    /// The value is never resolved for the variants of `#[repr(u128)]` enums, as
+    /// their discriminants may exceed [`i128::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let variant = core::r#enum::variant::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .discriminant("3")
+    ///                 .discriminant_value(3)
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(variant.discriminant_value(), Some(3));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn discriminant_value(&self) -> Option<i128> {
+        self.parts.discriminant_value
+    }
+
+    /// Gets the public name of the [`Variant`] by reference.
+    ///
+    /// This is the name provided via `#[introspect(rename = "...")]` or, if the variant
+    /// was not renamed, the identifier of the variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let variant = core::r#enum::variant::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .rename("Renamed")
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(variant.identifier(), "Name");
+    /// assert_eq!(variant.name(), "Renamed");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn name(&self) -> &str {
+        self.parts
+            .rename
+            .as_deref()
+            .unwrap_or(self.parts.identifier.as_str())
+    }
+
+    /// Gets whether the [`Variant`] is hidden (via `#[introspect(hidden)]`).
+    ///
+    /// Hidden variants are still introspected, but consumers are expected to leave them
+    /// out of user-facing output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let variant = core::r#enum::variant::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .hidden(true)
+    ///                 .try_build()?;
+    ///
+    /// assert!(variant.is_hidden());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn is_hidden(&self) -> bool {
+        self.parts.hidden
+    }
+
+    /// Gets the user-defined metadata of the [`Variant`] by reference.
+    ///
+    /// This is the metadata provided via `#[introspect(meta(..))]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let variant = core::r#enum::variant::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .metadata("unit", "ms")
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(variant.metadata().get("unit").and_then(|value| value.as_str()), Some("ms"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn metadata(&self) -> &Metadata {
+        &self.parts.metadata
+    }
+
+    /// Gets the location at which the [`Variant`] is defined by reference, if it is
+    /// known.
+    ///
+    /// The `Introspect` derive macro always provides the location.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let location = core::Location::new(
+    ///     "src/config.rs".into(),
+    ///     "my_crate::config".into(),
+    ///     42,
+    ///     1
+    /// );
+    ///
+    /// let variant = core::r#enum::variant::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .location(location)
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(variant.location().map(|location| location.line()), Some(42));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn location(&self) -> Option<&Location> {
+        self.parts.location.as_ref()
+    }
+
+    /// Gets the attributes of the [`Variant`] by reference.
+    ///
+    /// Attributes are only captured by the `Introspect` derive macro when the
+    /// `attributes` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let attribute: syn::Attribute = syn::parse_quote!(#[deprecated]);
+    ///
+    /// let variant = core::r#enum::variant::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .attribute(core::Attribute::from(&attribute))
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(variant.attributes()[0].path(), "deprecated");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn attributes(&self) -> &[Attribute] {
+        self.parts.attributes.as_slice()
     }
 
     /// Gets the documentation of the [`Variant`] by reference.
@@ -109,59 +664,147 @@ impl Variant {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn documentation(&self) -> Option<&str> {
-        self.documentation.as_deref()
+        self.parts.documentation.as_deref()
     }
-}
-
-impl std::fmt::Display for Variant {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "::introspect::r#enum::Variant::new(")?;
-        write!(f, "r#\"{}\"#.into(), ", self.identifier)?;
 
-        match self.documentation.as_ref() {
-            Some(documentation) => write!(f, "Some(r#\"{}\"#.into())", documentation)?,
-            None => write!(f, "None")?,
-        };
+    /// Gets the raw documentation of the [`Variant`] by reference.
+    ///
+    /// Unlike [`Variant::documentation()`], the text of each documentation attribute
+    /// is kept exactly as written: neither the common leading whitespace nor the
+    /// decoration of block comments (`/** .. */`) is stripped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let variant = core::r#enum::variant::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .documentation_raw(" Documentation.")
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(variant.documentation_raw(), Some(" Documentation."));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn documentation_raw(&self) -> Option<&str> {
+        self.parts.documentation_raw.as_deref()
+    }
 
-        write!(f, ")")
+    /// Gets the documentation of the [`Variant`] parsed into a summary, a body, and
+    /// sections (see [`Documentation`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let variant = core::r#enum::variant::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .documentation("The summary.\n\nThe body.\n\n# Panics\n\nNever.")
+    ///                 .try_build()?;
+    ///
+    /// let documentation = variant.documentation_parsed().unwrap();
+    /// assert_eq!(documentation.summary(), "The summary.");
+    /// assert_eq!(documentation.body(), "The body.");
+    /// assert_eq!(documentation.section("Panics").unwrap().content(), "Never.");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn documentation_parsed(&self) -> Option<Documentation<'_>> {
+        self.documentation().map(Documentation::new)
     }
 }
 
+#[cfg(feature = "parse")]
 impl TryFrom<&syn::Variant> for Variant {
     type Error = Error;
 
     fn try_from(value: &syn::Variant) -> Result<Self> {
-        let documentation = value
-            .attrs
+        let mut errors = Vec::new();
+
+        let options = match Options::try_from(value.attrs.as_slice()) {
+            Ok(options) => options,
+            Err(err) => {
+                errors.push(Error::InvalidOption(err));
+                Options::default()
+            }
+        };
+
+        if let Err(err) = options.deny_reflect("variants") {
+            errors.push(Error::InvalidOption(err));
+        }
+
+        if let Err(err) = options.deny_value("variants") {
+            errors.push(Error::InvalidOption(err));
+        }
+
+        let (documentation, documentation_raw) = crate::parse::documentation(
+            &value.attrs,
+            &mut errors,
+            Error::UnsupportedExpression,
+            Error::UnsupportedExpressionLiteral,
+        );
+
+        let fields = value
+            .fields
             .iter()
-            .filter_map(|attr| match attr.meta.require_name_value() {
-                Ok(v) => Some(v),
-                Err(_) => None,
+            .enumerate()
+            // NOTE: invalid options are reported when the field itself is converted.
+            .filter(|(_, field)| {
+                !Options::try_from(field.attrs.as_slice()).is_ok_and(|options| options.skip())
             })
-            .filter_map(|variant| {
-                variant
-                    .path
-                    .get_ident()
-                    .map(|ident| (ident, variant.value.clone()))
+            .filter_map(|(index, field)| match Field::try_from((index, field)) {
+                Ok(field) => Some(field),
+                Err(err) => {
+                    errors.push(Error::Field(err));
+                    None
+                }
             })
-            .filter(|(ident, _)| *ident == "doc")
-            .map(|(_, expr)| match expr {
-                syn::Expr::Lit(expr_lit) => match expr_lit.lit {
-                    syn::Lit::Str(lit_str) => Ok(lit_str.value().trim().to_string()),
-                    _ => Err(Error::UnsupportedExpressionLiteral(expr_lit)),
-                },
-                _ => Err(Error::UnsupportedExpression(expr)),
-            })
-            .collect::<Result<Vec<String>>>()?
-            .join("\n");
-
-        Ok(Self {
-            identifier: value.ident.to_string(),
-            documentation: match documentation.is_empty() {
-                true => None,
-                false => Some(documentation),
-            },
-        })
+            .collect::<Vec<_>>();
+
+        if let Some(err) = crate::parse::combine(errors, Error::Multiple) {
+            return Err(err);
+        }
+
+        Ok(Self::new(Parts {
+            identifier: value.ident.to_string().into(),
+            kind: VariantKind::from(&value.fields),
+            fields: fields.into(),
+            discriminant: value
+                .discriminant
+                .as_ref()
+                .map(|(_, expr)| crate::r#type::normalize(expr).into()),
+            discriminant_value: value
+                .discriminant
+                .as_ref()
+                .and_then(|(_, expr)| parse_integer(expr)),
+            attributes: crate::attribute::capture(&value.attrs).into(),
+            rename: options.rename().map(|rename| rename.to_string().into()),
+            hidden: options.hidden(),
+            metadata: options.metadata().clone(),
+            location: None,
+            documentation: documentation.map(Into::into),
+            documentation_raw: documentation_raw.map(Into::into),
+        }))
+    }
+}
+
+/// Parses an integer literal (optionally negated) into its value.
+#[cfg(feature = "parse")]
+fn parse_integer(expr: &syn::Expr) -> Option<i128> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(lit_int),
+            ..
+        }) => lit_int.base10_parse().ok(),
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => parse_integer(expr).map(|value| -value),
+        syn::Expr::Paren(syn::ExprParen { expr, .. }) => parse_integer(expr),
+        _ => None,
     }
 }
 
@@ -170,22 +813,101 @@ mod tests {
     use super::*;
 
     #[test]
-    fn display_identifier_and_documentation() {
-        let variant = Variant::new(String::from("Name"), Some(String::from("Documentation.")));
+    fn try_from_captures_the_discriminant() {
+        let item: syn::ItemEnum = syn::parse_quote! {
+            enum Foo {
+                A = 3,
+                B = -0x10,
+                C = 1 << 2,
+                D,
+            }
+        };
 
-        assert_eq!(
-            variant.to_string(),
-            "::introspect::r#enum::Variant::new(r#\"Name\"#.into(), Some(r#\"Documentation.\"#.into()))"
-        )
+        let variants = item
+            .variants
+            .iter()
+            .map(|variant| Variant::try_from(variant).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(variants[0].discriminant(), Some("3"));
+        assert_eq!(variants[0].discriminant_value(), Some(3));
+        assert_eq!(variants[1].discriminant(), Some("-0x10"));
+        assert_eq!(variants[1].discriminant_value(), Some(-16));
+        assert_eq!(variants[2].discriminant(), Some("1 << 2"));
+        assert_eq!(variants[2].discriminant_value(), None);
+        assert_eq!(variants[3].discriminant(), None);
+        assert_eq!(variants[3].discriminant_value(), None);
     }
 
     #[test]
-    fn display_only_identifier() {
-        let variant = Variant::new(String::from("Name"), None);
+    fn try_from_captures_the_kind_and_fields() {
+        let item: syn::ItemEnum = syn::parse_quote! {
+            enum Foo {
+                Unit,
+                Bar(u32),
+                Baz {
+                    /// The x.
+                    x: i32,
+                },
+            }
+        };
+
+        let variants = item
+            .variants
+            .iter()
+            .map(|variant| Variant::try_from(variant).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(variants[0].kind(), VariantKind::Unit);
+        assert!(variants[0].fields().is_empty());
+
+        assert_eq!(variants[1].kind(), VariantKind::Tuple);
+        assert_eq!(variants[1].fields()[0].identifier(), None);
+        assert_eq!(variants[1].fields()[0].index(), 0);
+        assert_eq!(variants[1].fields()[0].ty().text(), "u32");
+
+        assert_eq!(variants[2].kind(), VariantKind::Named);
+        assert_eq!(variants[2].fields()[0].identifier(), Some("x"));
+        assert_eq!(variants[2].fields()[0].documentation(), Some("The x."));
+    }
+
+    #[test]
+    fn try_from_reports_every_error() {
+        let variant: syn::Variant = syn::parse_quote! {
+            #[doc = 42]
+            #[doc = DOCUMENTATION]
+            Bar {
+                #[introspect(unknown)]
+                x: i32,
+            }
+        };
+
+        let err = Variant::try_from(&variant).unwrap_err();
+
+        match &err {
+            Error::Multiple(errors) => {
+                assert!(matches!(errors[0], Error::UnsupportedExpressionLiteral(_)));
+                assert!(matches!(errors[1], Error::UnsupportedExpression(_)));
+                assert!(matches!(
+                    errors[2],
+                    Error::Field(field::Error::InvalidOption(_))
+                ));
+            }
+            _ => unreachable!(),
+        }
+
+        let messages = syn::Error::from(err)
+            .into_iter()
+            .map(|err| err.to_string())
+            .collect::<Vec<_>>();
 
         assert_eq!(
-            variant.to_string(),
-            "::introspect::r#enum::Variant::new(r#\"Name\"#.into(), None)"
-        )
+            messages,
+            vec![
+                "unsupported doc attribute literal (expected a string literal)",
+                "unsupported doc attribute expression (expected a string literal)",
+                "unsupported `introspect` option",
+            ]
+        );
     }
 }
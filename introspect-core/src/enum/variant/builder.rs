@@ -1,4 +1,14 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::metadata::Value;
+use crate::r#enum::variant::Parts;
 use crate::r#enum::Variant;
+use crate::r#enum::VariantKind;
+use crate::r#struct::Field;
+use crate::Attribute;
+use crate::Location;
+use crate::Metadata;
 
 /// An error related to a [`Builder`].
 #[derive(Debug)]
@@ -7,18 +17,18 @@ pub enum Error {
     MissingIdentifier,
 }
 
-impl std::fmt::Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Error::MissingIdentifier => write!(f, "missing identifier"),
         }
     }
 }
 
-impl std::error::Error for Error {}
+impl core::error::Error for Error {}
 
-/// A [`Result`](std::result::Result) with an [`Error`].
-pub type Result<T> = std::result::Result<T, Error>;
+/// A [`Result`](core::result::Result) with an [`Error`].
+pub type Result<T> = core::result::Result<T, Error>;
 
 /// A builder for a [`Variant`].
 #[derive(Debug, Default)]
@@ -26,8 +36,38 @@ pub struct Builder {
     /// An identifier for the variant.
     identifier: Option<String>,
 
+    /// The shape of the variant.
+    kind: VariantKind,
+
+    /// The fields within the variant's payload.
+    fields: Vec<Field>,
+
+    /// The discriminant expression for the variant, if one was written.
+    discriminant: Option<String>,
+
+    /// The resolved discriminant value for the variant, if it is known.
+    discriminant_value: Option<i128>,
+
+    /// The attributes of the variant.
+    attributes: Vec<Attribute>,
+
+    /// The public name for the variant, if it was renamed.
+    rename: Option<String>,
+
+    /// Whether or not the variant is hidden.
+    hidden: bool,
+
+    /// The user-defined metadata for the variant.
+    metadata: Metadata,
+
+    /// The location at which the variant is defined, if it is known.
+    location: Option<Location>,
+
     /// The documentation for the variant, if it exists.
     documentation: Option<String>,
+
+    /// The raw documentation for the variant, if it exists.
+    documentation_raw: Option<String>,
 }
 
 impl Builder {
@@ -46,6 +86,157 @@ impl Builder {
         self
     }
 
+    /// Sets the shape for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::r#enum::variant::Builder::default()
+    ///                 .kind(core::r#enum::VariantKind::Named);
+    /// ```
+    pub fn kind(mut self, value: VariantKind) -> Self {
+        self.kind = value;
+        self
+    }
+
+    /// Adds a field to the payload for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let field = core::r#struct::field::Builder::default()
+    ///                 .ty(core::TypeRef::from(&syn::parse_quote!(u32)))
+    ///                 .try_build()?;
+    ///
+    /// let builder = core::r#enum::variant::Builder::default()
+    ///                 .kind(core::r#enum::VariantKind::Tuple)
+    ///                 .field(field);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn field(mut self, value: Field) -> Self {
+        self.fields.push(value);
+        self
+    }
+
+    /// Sets the discriminant expression for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::r#enum::variant::Builder::default()
+    ///                 .discriminant("3");
+    /// ```
+    pub fn discriminant<S: Into<String>>(mut self, value: S) -> Self {
+        self.discriminant = Some(value.into());
+        self
+    }
+
+    /// Sets the resolved discriminant value for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::r#enum::variant::Builder::default()
+    ///                 .discriminant_value(3);
+    /// ```
+    pub fn discriminant_value(mut self, value: i128) -> Self {
+        self.discriminant_value = Some(value);
+        self
+    }
+
+    /// Adds an attribute to this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let attribute: syn::Attribute = syn::parse_quote!(#[deprecated]);
+    ///
+    /// let builder = core::r#enum::variant::Builder::default()
+    ///                 .attribute(core::Attribute::from(&attribute));
+    /// ```
+    pub fn attribute(mut self, value: Attribute) -> Self {
+        self.attributes.push(value);
+        self
+    }
+
+    /// Sets the public name for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::r#enum::variant::Builder::default()
+    ///                 .rename("Renamed");
+    /// ```
+    pub fn rename<S: Into<String>>(mut self, value: S) -> Self {
+        self.rename = Some(value.into());
+        self
+    }
+
+    /// Sets whether the variant is hidden for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::r#enum::variant::Builder::default()
+    ///                 .hidden(true);
+    /// ```
+    pub fn hidden(mut self, value: bool) -> Self {
+        self.hidden = value;
+        self
+    }
+
+    /// Adds a user-defined metadata entry to this [`Builder`].
+    ///
+    /// If the key already exists, its value is replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::r#enum::variant::Builder::default()
+    ///                 .metadata("unit", "ms");
+    /// ```
+    pub fn metadata<K: Into<String>, V: Into<Value>>(mut self, key: K, value: V) -> Self {
+        self.metadata.insert(key, value);
+        self
+    }
+
+    /// Sets the location for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::r#enum::variant::Builder::default()
+    ///                 .location(core::Location::new(
+    ///                     "src/config.rs".into(),
+    ///                     "my_crate::config".into(),
+    ///                     42,
+    ///                     1
+    ///                 ));
+    /// ```
+    pub fn location(mut self, value: Location) -> Self {
+        self.location = Some(value);
+        self
+    }
+
     /// Sets the documentation for this [`Builder`].
     ///
     /// # Examples
@@ -61,6 +252,21 @@ impl Builder {
         self
     }
 
+    /// Sets the raw documentation for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::r#enum::variant::Builder::default()
+    ///                 .documentation_raw(" Documentation.");
+    /// ```
+    pub fn documentation_raw<S: Into<String>>(mut self, value: S) -> Self {
+        self.documentation_raw = Some(value.into());
+        self
+    }
+
     /// Consume `self` to produce an immutable [`Variant`].
     ///
     /// # Examples
@@ -81,9 +287,19 @@ impl Builder {
             None => return Err(Error::MissingIdentifier),
         };
 
-        Ok(Variant {
-            identifier,
-            documentation: self.documentation,
-        })
+        Ok(Variant::new(Parts {
+            identifier: identifier.into(),
+            kind: self.kind,
+            fields: self.fields.into(),
+            discriminant: self.discriminant.map(Into::into),
+            discriminant_value: self.discriminant_value,
+            attributes: self.attributes.into(),
+            rename: self.rename.map(Into::into),
+            hidden: self.hidden,
+            metadata: self.metadata,
+            location: self.location,
+            documentation: self.documentation.map(Into::into),
+            documentation_raw: self.documentation_raw.map(Into::into),
+        }))
     }
 }
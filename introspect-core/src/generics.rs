@@ -0,0 +1,436 @@
+//! Rust generics.
+
+#[cfg(feature = "parse")]
+use proc_macro2::TokenStream;
+#[cfg(feature = "parse")]
+use quote::quote;
+#[cfg(feature = "parse")]
+use quote::ToTokens;
+
+use crate::borrow::Slice;
+use crate::borrow::Str;
+#[cfg(feature = "parse")]
+use crate::r#type::normalize;
+#[cfg(feature = "parse")]
+use crate::r#type::option_tokens;
+#[cfg(feature = "parse")]
+use crate::r#type::slice_tokens;
+#[cfg(feature = "parse")]
+use crate::r#type::str_tokens;
+use crate::TypeRef;
+
+/// The generic parameters and where clause of a Rust entity.
+#[derive(Clone, Debug, Default)]
+pub struct Generics {
+    /// The generic parameters.
+    params: Slice<GenericParam>,
+
+    /// The predicates within the where clause.
+    where_predicates: Slice<WherePredicate>,
+}
+
+/// A generic parameter within [`Generics`].
+#[derive(Clone, Debug)]
+pub enum GenericParam {
+    /// A lifetime parameter, such as `'a: 'b`.
+    Lifetime {
+        /// The identifier for the lifetime (including the leading `'`).
+        identifier: Str,
+
+        /// The normalized source text of each bound on the lifetime.
+        bounds: Slice<Str>,
+    },
+
+    /// A type parameter, such as `T: Clone = String`.
+    Type {
+        /// The identifier for the type parameter.
+        identifier: Str,
+
+        /// The normalized source text of each bound on the type parameter.
+        bounds: Slice<Str>,
+
+        /// The default type for the type parameter, if it exists.
+        default: Option<TypeRef>,
+    },
+
+    /// A const parameter, such as `const N: usize = 4`.
+    Const {
+        /// The identifier for the const parameter.
+        identifier: Str,
+
+        /// The type of the const parameter.
+        ty: TypeRef,
+
+        /// The normalized source text of the default value, if it exists.
+        default: Option<Str>,
+    },
+}
+
+/// A predicate within the where clause of [`Generics`], such as `T: Default`.
+#[derive(Clone, Debug)]
+pub struct WherePredicate {
+    /// The normalized source text of the bounded type or lifetime.
+    bounded: Str,
+
+    /// The normalized source text of each bound.
+    bounds: Slice<Str>,
+}
+
+impl Generics {
+    /// Creates a new [`Generics`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    /// use core::borrow::Slice;
+    ///
+    /// let generics = core::Generics::new(Slice::Borrowed(&[]), Slice::Borrowed(&[]));
+    /// ```
+    pub const fn new(params: Slice<GenericParam>, where_predicates: Slice<WherePredicate>) -> Self {
+        Self {
+            params,
+            where_predicates,
+        }
+    }
+
+    /// Gets the generic parameters by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    /// use core::generics::GenericParam;
+    ///
+    /// let generics = core::Generics::from(&syn::parse_quote!(<'a, T: Clone, const N: usize>));
+    ///
+    /// assert_eq!(generics.params().len(), 3);
+    /// assert!(matches!(&generics.params()[1], GenericParam::Type { identifier, .. } if identifier == "T"));
+    /// ```
+    pub fn params(&self) -> &[GenericParam] {
+        self.params.as_slice()
+    }
+
+    /// Gets the predicates within the where clause by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let mut generics: syn::Generics = syn::parse_quote!(<T>);
+    /// generics.where_clause = Some(syn::parse_quote!(where T: Default));
+    ///
+    /// let generics = core::Generics::from(&generics);
+    ///
+    /// assert_eq!(generics.where_predicates()[0].bounded(), "T");
+    /// assert_eq!(generics.where_predicates()[0].bounds(), ["Default"]);
+    /// ```
+    pub fn where_predicates(&self) -> &[WherePredicate] {
+        self.where_predicates.as_slice()
+    }
+
+    /// Returns whether there are no generic parameters and no where predicates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// assert!(core::Generics::default().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty() && self.where_predicates.is_empty()
+    }
+}
+
+impl WherePredicate {
+    /// Creates a new [`WherePredicate`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    /// use core::borrow::Slice;
+    /// use core::borrow::Str;
+    ///
+    /// let predicate = core::generics::WherePredicate::new(
+    ///     Str::Borrowed("T"),
+    ///     Slice::Borrowed(&[Str::Borrowed("Default")])
+    /// );
+    /// ```
+    pub const fn new(bounded: Str, bounds: Slice<Str>) -> Self {
+        Self { bounded, bounds }
+    }
+
+    /// Gets the normalized source text of the bounded type or lifetime by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    /// use core::borrow::Slice;
+    /// use core::borrow::Str;
+    ///
+    /// let predicate = core::generics::WherePredicate::new(
+    ///     Str::Borrowed("T"),
+    ///     Slice::Borrowed(&[Str::Borrowed("Default")])
+    /// );
+    ///
+    /// assert_eq!(predicate.bounded(), "T");
+    /// ```
+    pub fn bounded(&self) -> &str {
+        self.bounded.as_str()
+    }
+
+    /// Gets the normalized source text of each bound by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    /// use core::borrow::Slice;
+    /// use core::borrow::Str;
+    ///
+    /// let predicate = core::generics::WherePredicate::new(
+    ///     Str::Borrowed("T"),
+    ///     Slice::Borrowed(&[Str::Borrowed("Default")])
+    /// );
+    ///
+    /// assert_eq!(predicate.bounds(), ["Default"]);
+    /// ```
+    pub fn bounds(&self) -> &[Str] {
+        self.bounds.as_slice()
+    }
+}
+
+#[cfg(feature = "parse")]
+impl ToTokens for Generics {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let params = slice_tokens(self.params.iter());
+        let where_predicates = slice_tokens(self.where_predicates.iter());
+
+        tokens.extend(quote!(::introspect::generics::Generics::new(#params, #where_predicates)))
+    }
+}
+
+#[cfg(feature = "parse")]
+impl ToTokens for GenericParam {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            GenericParam::Lifetime { identifier, bounds } => {
+                let identifier = str_tokens(identifier);
+                let bounds = slice_tokens(bounds.iter().map(|bound| str_tokens(bound)));
+
+                quote! {
+                    ::introspect::generics::GenericParam::Lifetime {
+                        identifier: #identifier,
+                        bounds: #bounds
+                    }
+                }
+            }
+            GenericParam::Type {
+                identifier,
+                bounds,
+                default,
+            } => {
+                let identifier = str_tokens(identifier);
+                let bounds = slice_tokens(bounds.iter().map(|bound| str_tokens(bound)));
+                let default = option_tokens(default.as_ref());
+
+                quote! {
+                    ::introspect::generics::GenericParam::Type {
+                        identifier: #identifier,
+                        bounds: #bounds,
+                        default: #default
+                    }
+                }
+            }
+            GenericParam::Const {
+                identifier,
+                ty,
+                default,
+            } => {
+                let identifier = str_tokens(identifier);
+                let default = option_tokens(default.as_deref().map(str_tokens));
+
+                quote! {
+                    ::introspect::generics::GenericParam::Const {
+                        identifier: #identifier,
+                        ty: #ty,
+                        default: #default
+                    }
+                }
+            }
+        })
+    }
+}
+
+#[cfg(feature = "parse")]
+impl ToTokens for WherePredicate {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let bounded = str_tokens(&self.bounded);
+        let bounds = slice_tokens(self.bounds.iter().map(|bound| str_tokens(bound)));
+
+        tokens.extend(quote!(::introspect::generics::WherePredicate::new(#bounded, #bounds)))
+    }
+}
+
+#[cfg(feature = "parse")]
+impl From<&syn::Generics> for Generics {
+    fn from(value: &syn::Generics) -> Self {
+        let params = value
+            .params
+            .iter()
+            .map(|param| match param {
+                syn::GenericParam::Lifetime(param) => GenericParam::Lifetime {
+                    identifier: param.lifetime.to_string().into(),
+                    bounds: param
+                        .bounds
+                        .iter()
+                        .map(|bound| normalize(bound).into())
+                        .collect(),
+                },
+                syn::GenericParam::Type(param) => GenericParam::Type {
+                    identifier: param.ident.to_string().into(),
+                    bounds: param
+                        .bounds
+                        .iter()
+                        .map(|bound| normalize(bound).into())
+                        .collect(),
+                    default: param.default.as_ref().map(TypeRef::from),
+                },
+                syn::GenericParam::Const(param) => GenericParam::Const {
+                    identifier: param.ident.to_string().into(),
+                    ty: TypeRef::from(&param.ty),
+                    default: param
+                        .default
+                        .as_ref()
+                        .map(|default| normalize(default).into()),
+                },
+            })
+            .collect();
+
+        let where_predicates = value
+            .where_clause
+            .iter()
+            .flat_map(|where_clause| where_clause.predicates.iter())
+            .filter_map(|predicate| match predicate {
+                syn::WherePredicate::Lifetime(predicate) => Some(WherePredicate {
+                    bounded: predicate.lifetime.to_string().into(),
+                    bounds: predicate
+                        .bounds
+                        .iter()
+                        .map(|bound| normalize(bound).into())
+                        .collect(),
+                }),
+                syn::WherePredicate::Type(predicate) => Some(WherePredicate {
+                    bounded: normalize(&predicate.bounded_ty).into(),
+                    bounds: predicate
+                        .bounds
+                        .iter()
+                        .map(|bound| normalize(bound).into())
+                        .collect(),
+                }),
+                _ => None,
+            })
+            .collect();
+
+        Self {
+            params,
+            where_predicates,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_captures_params_and_where_predicates() {
+        let item: syn::ItemStruct = syn::parse_quote! {
+            struct Wrapper<'a: 'b, 'b, T: Clone + ?Sized = String, const N: usize = 4>
+            where
+                T: Default,
+                'b: 'a,
+            {
+                inner: &'a T,
+            }
+        };
+
+        let generics = Generics::from(&item.generics);
+
+        match &generics.params()[0] {
+            GenericParam::Lifetime { identifier, bounds } => {
+                assert_eq!(identifier, "'a");
+                assert_eq!(bounds[..], ["'b"]);
+            }
+            _ => unreachable!(),
+        }
+
+        match &generics.params()[2] {
+            GenericParam::Type {
+                identifier,
+                bounds,
+                default,
+            } => {
+                assert_eq!(identifier, "T");
+                assert_eq!(bounds[..], ["Clone", "?Sized"]);
+                assert_eq!(default.as_ref().map(|ty| ty.text()), Some("String"));
+            }
+            _ => unreachable!(),
+        }
+
+        match &generics.params()[3] {
+            GenericParam::Const {
+                identifier,
+                ty,
+                default,
+            } => {
+                assert_eq!(identifier, "N");
+                assert_eq!(ty.text(), "usize");
+                assert_eq!(default.as_deref(), Some("4"));
+            }
+            _ => unreachable!(),
+        }
+
+        assert_eq!(generics.where_predicates().len(), 2);
+        assert_eq!(generics.where_predicates()[0].bounded(), "T");
+        assert_eq!(generics.where_predicates()[0].bounds(), ["Default"]);
+        assert_eq!(generics.where_predicates()[1].bounded(), "'b");
+        assert_eq!(generics.where_predicates()[1].bounds(), ["'a"]);
+    }
+
+    #[test]
+    fn converts_to_tokens_correctly() {
+        let generics = Generics::from(&syn::parse_quote!(<'a, T: Clone>));
+
+        assert_eq!(
+            generics.to_token_stream().to_string(),
+            quote! {
+                ::introspect::generics::Generics::new(
+                    ::introspect::borrow::Slice::Borrowed(
+                        const {
+                            &[
+                                ::introspect::generics::GenericParam::Lifetime {
+                                    identifier: ::introspect::borrow::Str::Borrowed("'a"),
+                                    bounds: ::introspect::borrow::Slice::Borrowed(const { &[] })
+                                },
+                                ::introspect::generics::GenericParam::Type {
+                                    identifier: ::introspect::borrow::Str::Borrowed("T"),
+                                    bounds: ::introspect::borrow::Slice::Borrowed(
+                                        const { &[::introspect::borrow::Str::Borrowed("Clone")] }
+                                    ),
+                                    default: ::core::option::Option::None
+                                }
+                            ]
+                        }
+                    ),
+                    ::introspect::borrow::Slice::Borrowed(const { &[] })
+                )
+            }
+            .to_string()
+        );
+    }
+}
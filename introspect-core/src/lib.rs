@@ -1,19 +1,68 @@
 //! A crate containing the core functionality used for `introspect` and supporting
 //! crates.
+//!
+//! ## Features
+//!
+//! * `std` (enabled by default): links against the standard library. Implies
+//!   `alloc`.
+//! * `alloc`: allows the model types to own their data and provides the builders.
+//! * `parse` (enabled by default): parses entities and members from their syntax
+//!   trees and converts them back into tokens (as needed by the `Introspect`
+//!   derive macro). Pulls in `syn`, `quote`, and `proc-macro2`. Implies `std`.
+//! * `attributes`: captures the attributes (other than documentation) of each
+//!   entity and member when parsing. Implies `parse`.
+//!
+//! The `introspect` crate depends on this crate without default features, so only
+//! `introspect-proc-macros` enables `parse`: the model types that are used at
+//! runtime carry no proc-macro dependencies.
+//!
+//! Without `std` and `alloc`, the crate is `#![no_std]` and does not require an
+//! allocator: the model types can then only borrow `'static` data, such as the
+//! descriptors that are embedded by the `Introspect` derive macro.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![warn(missing_docs)]
 #![warn(rust_2018_idioms)]
 #![warn(rust_2021_compatibility)]
 #![warn(missing_debug_implementations)]
 #![warn(rustdoc::broken_intra_doc_links)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub mod attribute;
+pub mod borrow;
+mod descriptor;
+pub mod documentation;
 mod entity;
 pub mod r#enum;
+pub mod generics;
+pub mod location;
 mod member;
+pub mod metadata;
+#[cfg(feature = "parse")]
+pub mod options;
+#[cfg(feature = "parse")]
+pub mod parse;
 pub mod r#struct;
+pub mod r#type;
+pub mod union;
+pub mod visibility;
 
+pub use attribute::Attribute;
+pub use descriptor::EntityDescriptor;
+pub use documentation::Documentation;
 pub use entity::Entity;
+pub use generics::Generics;
+pub use location::Location;
 pub use member::Member;
+pub use member::Members;
+pub use metadata::Metadata;
+#[cfg(feature = "parse")]
+pub use options::Options;
 
 pub use r#enum::Enum;
 pub use r#struct::Struct;
+pub use r#type::TypeRef;
+pub use union::Union;
+pub use visibility::Visibility;
@@ -0,0 +1,172 @@
+//! Source locations.
+
+#[cfg(feature = "parse")]
+use proc_macro2::Literal;
+#[cfg(feature = "parse")]
+use proc_macro2::TokenStream;
+#[cfg(feature = "parse")]
+use quote::quote;
+#[cfg(feature = "parse")]
+use quote::ToTokens;
+
+use crate::borrow::Str;
+#[cfg(feature = "parse")]
+use crate::r#type::str_tokens;
+
+/// The location within the source code at which a Rust entity or member is defined.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Location {
+    /// The path of the source file (as reported by `file!()`).
+    file: Str,
+
+    /// The path of the enclosing module (as reported by `module_path!()`).
+    module_path: Str,
+
+    /// The one-based line number (as reported by `line!()`).
+    line: u32,
+
+    /// The one-based column number (as reported by `column!()`).
+    column: u32,
+}
+
+impl Location {
+    /// Creates a new [`Location`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let location = core::Location::new(
+    ///     "src/config.rs".into(),
+    ///     "my_crate::config".into(),
+    ///     42,
+    ///     1
+    /// );
+    /// ```
+    pub const fn new(file: Str, module_path: Str, line: u32, column: u32) -> Self {
+        Self {
+            file,
+            module_path,
+            line,
+            column,
+        }
+    }
+
+    /// Gets the path of the source file by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let location = core::Location::new(
+    ///     "src/config.rs".into(),
+    ///     "my_crate::config".into(),
+    ///     42,
+    ///     1
+    /// );
+    ///
+    /// assert_eq!(location.file(), "src/config.rs");
+    /// ```
+    pub fn file(&self) -> &str {
+        self.file.as_str()
+    }
+
+    /// Gets the path of the enclosing module by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let location = core::Location::new(
+    ///     "src/config.rs".into(),
+    ///     "my_crate::config".into(),
+    ///     42,
+    ///     1
+    /// );
+    ///
+    /// assert_eq!(location.module_path(), "my_crate::config");
+    /// ```
+    pub fn module_path(&self) -> &str {
+        self.module_path.as_str()
+    }
+
+    /// Gets the one-based line number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let location = core::Location::new(
+    ///     "src/config.rs".into(),
+    ///     "my_crate::config".into(),
+    ///     42,
+    ///     1
+    /// );
+    ///
+    /// assert_eq!(location.line(), 42);
+    /// ```
+    pub fn line(&self) -> u32 {
+        self.line
+    }
+
+    /// Gets the one-based column number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let location = core::Location::new(
+    ///     "src/config.rs".into(),
+    ///     "my_crate::config".into(),
+    ///     42,
+    ///     1
+    /// );
+    ///
+    /// assert_eq!(location.column(), 1);
+    /// ```
+    pub fn column(&self) -> u32 {
+        self.column
+    }
+}
+
+#[cfg(feature = "parse")]
+impl ToTokens for Location {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let file = str_tokens(&self.file);
+        let module_path = str_tokens(&self.module_path);
+        let line = Literal::u32_unsuffixed(self.line);
+        let column = Literal::u32_unsuffixed(self.column);
+
+        tokens.extend(quote! {
+            ::introspect::location::Location::new(#file, #module_path, #line, #column)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_to_tokens_correctly() {
+        let location = Location::new("src/config.rs".into(), "my_crate::config".into(), 42, 5);
+
+        assert_eq!(
+            location.to_token_stream().to_string(),
+            quote! {
+                ::introspect::location::Location::new(
+                    ::introspect::borrow::Str::Borrowed("src/config.rs"),
+                    ::introspect::borrow::Str::Borrowed("my_crate::config"),
+                    42,
+                    5
+                )
+            }
+            .to_string()
+        );
+    }
+}
@@ -1,54 +1,230 @@
+#[cfg(feature = "parse")]
 use proc_macro2::TokenStream;
+#[cfg(feature = "parse")]
+use quote::quote;
+#[cfg(feature = "parse")]
 use quote::ToTokens;
 
 use crate::r#enum::Variant;
 use crate::r#struct::Field;
 
 /// A member of a Rust construct.
-#[derive(Debug)]
-pub enum Member {
+#[derive(Clone, Copy, Debug)]
+pub enum Member<'a> {
     /// A struct field.
-    Field(Field),
+    Field(&'a Field),
 
     /// An enum variant.
-    Variant(Variant),
+    Variant(&'a Variant),
 }
 
-impl std::fmt::Display for Member {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl Member<'_> {
+    /// Returns whether the [`Member`] is public.
+    ///
+    /// Fields are public when they are declared `pub`. Variants are always public, as
+    /// they share the visibility of their enum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let field = core::r#struct::field::Builder::default()
+    ///                 .ty(core::TypeRef::from(&syn::parse_quote!(u16)))
+    ///                 .visibility(core::Visibility::Crate)
+    ///                 .try_build()?;
+    ///
+    /// assert!(!core::Member::Field(&field).is_public());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn is_public(&self) -> bool {
         match self {
-            Member::Field(field) => write!(f, "::introspect::Member::Field({})", field),
-            Member::Variant(variant) => write!(f, "::introspect::Member::Variant({})", variant),
+            Member::Field(field) => field.visibility().is_public(),
+            Member::Variant(_) => true,
+        }
+    }
+
+    /// Returns whether the [`Member`] is hidden (via `#[introspect(hidden)]`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let variant = core::r#enum::variant::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .hidden(true)
+    ///                 .try_build()?;
+    ///
+    /// assert!(core::Member::Variant(&variant).is_hidden());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn is_hidden(&self) -> bool {
+        match self {
+            Member::Field(field) => field.is_hidden(),
+            Member::Variant(variant) => variant.is_hidden(),
         }
     }
 }
 
-impl ToTokens for Member {
+#[cfg(feature = "parse")]
+impl ToTokens for Member<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        // SAFETY: this unwrap should never fail as we exhaustively test converting a
-        // [`Member`] to a string that eventually parses to a token stream.
-        tokens.extend(self.to_string().parse::<TokenStream>().unwrap())
+        // NOTE: the member is borrowed from a constant so that it lives for `'static`,
+        // in the same way that slices of fields and variants are.
+        tokens.extend(match self {
+            Member::Field(field) => quote!(::introspect::Member::Field(const { &#field })),
+            Member::Variant(variant) => quote!(::introspect::Member::Variant(const { &#variant })),
+        })
+    }
+}
+
+/// An iterator over the members of an [`Entity`](crate::Entity) (see
+/// [`Entity::members()`](crate::Entity::members)).
+#[derive(Clone, Debug)]
+pub struct Members<'a> {
+    /// The remaining members.
+    inner: Inner<'a>,
+}
+
+/// The remaining members of a [`Members`] iterator.
+#[derive(Clone, Debug)]
+enum Inner<'a> {
+    /// The remaining fields of a struct or union.
+    Fields(core::slice::Iter<'a, Field>),
+
+    /// The remaining variants of an enum.
+    Variants(core::slice::Iter<'a, Variant>),
+}
+
+impl<'a> Members<'a> {
+    /// Creates a new [`Members`] iterator over fields.
+    pub(crate) fn fields(fields: &'a [Field]) -> Self {
+        Self {
+            inner: Inner::Fields(fields.iter()),
+        }
+    }
+
+    /// Creates a new [`Members`] iterator over variants.
+    pub(crate) fn variants(variants: &'a [Variant]) -> Self {
+        Self {
+            inner: Inner::Variants(variants.iter()),
+        }
     }
 }
 
+impl<'a> Iterator for Members<'a> {
+    type Item = Member<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.inner {
+            Inner::Fields(fields) => fields.next().map(Member::Field),
+            Inner::Variants(variants) => variants.next().map(Member::Variant),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.inner {
+            Inner::Fields(fields) => fields.size_hint(),
+            Inner::Variants(variants) => variants.size_hint(),
+        }
+    }
+}
+
+impl DoubleEndedIterator for Members<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match &mut self.inner {
+            Inner::Fields(fields) => fields.next_back().map(Member::Field),
+            Inner::Variants(variants) => variants.next_back().map(Member::Variant),
+        }
+    }
+}
+
+impl ExactSizeIterator for Members<'_> {}
+
+impl core::iter::FusedIterator for Members<'_> {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
-    fn an_enum_variant_converts_to_string_correctly() {
-        let member = Member::Variant(Variant::new("Name".into(), Some("Documentation.".into())));
+    fn a_field_converts_to_tokens_correctly() {
+        let field = crate::r#struct::field::Builder::default()
+            .identifier("port")
+            .ty(crate::TypeRef::from(&syn::parse_quote!(u16)))
+            .try_build()
+            .unwrap();
 
-        assert_eq!(member.to_string(), "::introspect::Member::Variant(::introspect::r#enum::Variant::new(r#\"Name\"#.into(), Some(r#\"Documentation.\"#.into())))");
+        assert_eq!(
+            Member::Field(&field).to_token_stream().to_string(),
+            quote! {
+                ::introspect::Member::Field(const {
+                    &::introspect::r#struct::Field::new(::introspect::r#struct::field::Parts {
+                        identifier: ::core::option::Option::Some(
+                            ::introspect::borrow::Str::Borrowed("port")
+                        ),
+                        index: 0,
+                        ty: ::introspect::r#type::TypeRef::new(
+                            ::introspect::borrow::Str::Borrowed("u16"),
+                            ::introspect::r#type::Kind::Path(::introspect::borrow::Slice::Borrowed(
+                                const {
+                                    &[::introspect::r#type::PathSegment::new(
+                                        ::introspect::borrow::Str::Borrowed("u16"),
+                                        ::introspect::borrow::Slice::Borrowed(const { &[] })
+                                    )]
+                                }
+                            ))
+                        ),
+                        visibility: ::introspect::visibility::Visibility::Private,
+                        attributes: ::introspect::borrow::Slice::Borrowed(const { &[] }),
+                        rename: ::core::option::Option::None,
+                        hidden: false,
+                        metadata: ::introspect::metadata::Metadata::new(
+                            ::introspect::borrow::Slice::Borrowed(const { &[] })
+                        ),
+                        location: ::core::option::Option::None,
+                        documentation: ::core::option::Option::None,
+                        documentation_raw: ::core::option::Option::None,
+                    })
+                })
+            }
+            .to_string()
+        );
     }
 
     #[test]
-    fn a_struct_field_converts_to_string_correctly() {
-        let member = Member::Field(Field::new(
-            Some("Name".into()),
-            Some("Documentation.".into()),
-        ));
+    fn a_variant_converts_to_tokens_correctly() {
+        let variant = crate::r#enum::variant::Builder::default()
+            .identifier("One")
+            .try_build()
+            .unwrap();
 
-        assert_eq!(member.to_string(), "::introspect::Member::Field(::introspect::r#struct::Field::new(Some(r#\"Name\"#.into()), Some(r#\"Documentation.\"#.into())))");
+        assert_eq!(
+            Member::Variant(&variant).to_token_stream().to_string(),
+            quote! {
+                ::introspect::Member::Variant(const {
+                    &::introspect::r#enum::Variant::new(::introspect::r#enum::variant::Parts {
+                        identifier: ::introspect::borrow::Str::Borrowed("One"),
+                        kind: ::introspect::r#enum::VariantKind::Unit,
+                        fields: ::introspect::borrow::Slice::Borrowed(const { &[] }),
+                        discriminant: ::core::option::Option::None,
+                        discriminant_value: ::core::option::Option::None,
+                        attributes: ::introspect::borrow::Slice::Borrowed(const { &[] }),
+                        rename: ::core::option::Option::None,
+                        hidden: false,
+                        metadata: ::introspect::metadata::Metadata::new(
+                            ::introspect::borrow::Slice::Borrowed(const { &[] })
+                        ),
+                        location: ::core::option::Option::None,
+                        documentation: ::core::option::Option::None,
+                        documentation_raw: ::core::option::Option::None,
+                    })
+                })
+            }
+            .to_string()
+        );
     }
 }
@@ -0,0 +1,441 @@
+//! User-defined metadata.
+//!
+//! Metadata is provided via the `meta(..)` option of the `#[introspect(..)]` helper
+//! attribute, such as `#[introspect(meta(unit = "ms", min = 0, experimental = true))]`.
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::string::ToString;
+
+#[cfg(feature = "parse")]
+use proc_macro2::Literal;
+#[cfg(feature = "parse")]
+use proc_macro2::TokenStream;
+#[cfg(feature = "parse")]
+use quote::quote;
+#[cfg(feature = "parse")]
+use quote::ToTokens;
+
+use crate::borrow::Slice;
+use crate::borrow::Str;
+#[cfg(feature = "parse")]
+use crate::r#type::slice_tokens;
+#[cfg(feature = "parse")]
+use crate::r#type::str_tokens;
+
+/// A typed value within [`Metadata`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    /// A string value, such as `unit = "ms"`.
+    String(Str),
+
+    /// An integer value, such as `min = 0` or `offset = -10`.
+    Int(i128),
+
+    /// A floating point value, such as `scale = 0.5`.
+    Float(f64),
+
+    /// A boolean value, such as `experimental = true`.
+    Bool(bool),
+}
+
+impl Value {
+    /// Gets the value as a string slice, if it is a [`Value::String`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    /// use core::metadata::Value;
+    ///
+    /// assert_eq!(Value::from("ms").as_str(), Some("ms"));
+    /// assert_eq!(Value::from(true).as_str(), None);
+    /// ```
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(value) => Some(value.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// Gets the value as an integer, if it is a [`Value::Int`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    /// use core::metadata::Value;
+    ///
+    /// assert_eq!(Value::from(65535).as_int(), Some(65535));
+    /// assert_eq!(Value::from(0.5).as_int(), None);
+    /// ```
+    pub fn as_int(&self) -> Option<i128> {
+        match self {
+            Value::Int(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Gets the value as a floating point number, if it is a [`Value::Float`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    /// use core::metadata::Value;
+    ///
+    /// assert_eq!(Value::from(0.5).as_float(), Some(0.5));
+    /// assert_eq!(Value::from(1).as_float(), None);
+    /// ```
+    pub fn as_float(&self) -> Option<f64> {
+        match self {
+            Value::Float(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Gets the value as a boolean, if it is a [`Value::Bool`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    /// use core::metadata::Value;
+    ///
+    /// assert_eq!(Value::from(true).as_bool(), Some(true));
+    /// assert_eq!(Value::from("true").as_bool(), None);
+    /// ```
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Value::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::String(Str::Owned(value.to_string()))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::String(Str::Owned(value))
+    }
+}
+
+impl From<f64> for Value {
+    fn from(value: f64) -> Self {
+        Value::Float(value)
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Value::Bool(value)
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Value {
+                fn from(value: $ty) -> Self {
+                    Value::Int(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+
+#[cfg(feature = "parse")]
+impl ToTokens for Value {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Value::String(value) => {
+                let value = str_tokens(value);
+                quote!(::introspect::metadata::Value::String(#value))
+            }
+            Value::Int(value) => {
+                let value = Literal::i128_unsuffixed(*value);
+                quote!(::introspect::metadata::Value::Int(#value))
+            }
+            // NOTE: an unsuffixed float literal always includes a decimal point or an
+            // exponent, so the value is never mistaken for an integer literal.
+            Value::Float(value) => {
+                let value = Literal::f64_unsuffixed(*value);
+                quote!(::introspect::metadata::Value::Float(#value))
+            }
+            Value::Bool(value) => quote!(::introspect::metadata::Value::Bool(#value)),
+        })
+    }
+}
+
+#[cfg(feature = "parse")]
+impl ToTokens for Metadata {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let entries = slice_tokens(self.entries.iter().map(|(key, value)| {
+            let key = str_tokens(key);
+            quote!((#key, #value))
+        }));
+
+        tokens.extend(quote!(::introspect::metadata::Metadata::new(#entries)))
+    }
+}
+
+#[cfg(feature = "parse")]
+impl TryFrom<&syn::Expr> for Value {
+    type Error = syn::Error;
+
+    fn try_from(value: &syn::Expr) -> syn::Result<Self> {
+        let unsupported = || {
+            syn::Error::new_spanned(
+                value,
+                "metadata values must be string, integer, float, or boolean literals",
+            )
+        };
+
+        match value {
+            syn::Expr::Lit(expr_lit) => match &expr_lit.lit {
+                syn::Lit::Str(lit) => Ok(Value::from(lit.value())),
+                syn::Lit::Int(lit) => Ok(Value::Int(lit.base10_parse()?)),
+                syn::Lit::Float(lit) => Ok(Value::Float(lit.base10_parse()?)),
+                syn::Lit::Bool(lit) => Ok(Value::Bool(lit.value)),
+                _ => Err(unsupported()),
+            },
+            syn::Expr::Unary(syn::ExprUnary {
+                op: syn::UnOp::Neg(_),
+                expr,
+                ..
+            }) => match Value::try_from(expr.as_ref())? {
+                Value::Int(value) => Ok(Value::Int(-value)),
+                Value::Float(value) => Ok(Value::Float(-value)),
+                _ => Err(unsupported()),
+            },
+            _ => Err(unsupported()),
+        }
+    }
+}
+
+/// The user-defined metadata of an entity or member, keyed by name.
+///
+/// Entries are kept in the order in which they were declared.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Metadata {
+    /// The entries within the metadata.
+    entries: Slice<(Str, Value)>,
+}
+
+impl Metadata {
+    /// Creates a new [`Metadata`].
+    ///
+    /// The keys are expected to be unique: if a key is provided more than once, only
+    /// the first value is returned by [`Metadata::get()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    /// use core::borrow::Slice;
+    /// use core::borrow::Str;
+    /// use core::metadata::Value;
+    ///
+    /// const METADATA: core::Metadata = core::Metadata::new(Slice::Borrowed(&[
+    ///     (Str::Borrowed("unit"), Value::String(Str::Borrowed("ms"))),
+    ///     (Str::Borrowed("max"), Value::Int(65535)),
+    /// ]));
+    ///
+    /// assert_eq!(METADATA.len(), 2);
+    /// ```
+    pub const fn new(entries: Slice<(Str, Value)>) -> Self {
+        Self { entries }
+    }
+
+    /// Inserts a value into the [`Metadata`], returning the previous value for the key
+    /// (if it existed).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    /// use core::metadata::Value;
+    ///
+    /// let mut metadata = core::Metadata::default();
+    ///
+    /// assert_eq!(metadata.insert("unit", "ms"), None);
+    /// assert_eq!(metadata.insert("unit", "s"), Some(Value::from("ms")));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn insert<K: Into<String>, V: Into<Value>>(&mut self, key: K, value: V) -> Option<Value> {
+        let key = key.into();
+        let value = value.into();
+        let entries = self.entries.to_mut();
+
+        match entries.iter_mut().find(|(k, _)| k.as_str() == key) {
+            Some((_, existing)) => Some(core::mem::replace(existing, value)),
+            None => {
+                entries.push((Str::Owned(key), value));
+                None
+            }
+        }
+    }
+
+    /// Gets the value for a key by reference, if it exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let mut metadata = core::Metadata::default();
+    /// metadata.insert("unit", "ms");
+    ///
+    /// assert_eq!(metadata.get("unit").and_then(|value| value.as_str()), Some("ms"));
+    /// assert_eq!(metadata.get("min"), None);
+    /// ```
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.entries
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value)
+    }
+
+    /// Returns whether the [`Metadata`] contains a key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let mut metadata = core::Metadata::default();
+    /// metadata.insert("experimental", true);
+    ///
+    /// assert!(metadata.contains_key("experimental"));
+    /// ```
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Gets an iterator over the entries of the [`Metadata`] in declaration order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let mut metadata = core::Metadata::default();
+    /// metadata.insert("unit", "ms");
+    /// metadata.insert("min", 0);
+    ///
+    /// let keys = metadata.iter().map(|(key, _)| key).collect::<Vec<_>>();
+    /// assert_eq!(keys, ["unit", "min"]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Value)> {
+        self.entries
+            .iter()
+            .map(|(key, value)| (key.as_ref(), value))
+    }
+
+    /// Gets the number of entries within the [`Metadata`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// assert_eq!(core::Metadata::default().len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether the [`Metadata`] has no entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// assert!(core::Metadata::default().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn value_try_from_keeps_the_type() {
+        let value = |expr: syn::Expr| Value::try_from(&expr).unwrap();
+
+        assert_eq!(value(syn::parse_quote!("ms")), Value::from("ms"));
+        assert_eq!(value(syn::parse_quote!(65535)), Value::Int(65535));
+        assert_eq!(value(syn::parse_quote!(-10i8)), Value::Int(-10));
+        assert_eq!(value(syn::parse_quote!(0.5)), Value::Float(0.5));
+        assert_eq!(value(syn::parse_quote!(-1e3)), Value::Float(-1000.0));
+        assert_eq!(value(syn::parse_quote!(true)), Value::Bool(true));
+    }
+
+    #[test]
+    fn value_try_from_rejects_other_expressions() {
+        let expr: syn::Expr = syn::parse_quote!(1 + 2);
+        assert!(Value::try_from(&expr).is_err());
+
+        let expr: syn::Expr = syn::parse_quote!(-"ms");
+        assert!(Value::try_from(&expr).is_err());
+
+        let expr: syn::Expr = syn::parse_quote!(b'a');
+        assert!(Value::try_from(&expr).is_err());
+    }
+
+    #[test]
+    fn converts_to_tokens_correctly() {
+        let mut metadata = Metadata::default();
+        metadata.insert("unit", "ms");
+        metadata.insert("min", -1);
+        metadata.insert("scale", 1.0);
+        metadata.insert("experimental", true);
+
+        assert_eq!(
+            metadata.to_token_stream().to_string(),
+            quote! {
+                ::introspect::metadata::Metadata::new(
+                    ::introspect::borrow::Slice::Borrowed(
+                        const {
+                            &[
+                                (
+                                    ::introspect::borrow::Str::Borrowed("unit"),
+                                    ::introspect::metadata::Value::String(
+                                        ::introspect::borrow::Str::Borrowed("ms")
+                                    )
+                                ),
+                                (
+                                    ::introspect::borrow::Str::Borrowed("min"),
+                                    ::introspect::metadata::Value::Int(-1)
+                                ),
+                                (
+                                    ::introspect::borrow::Str::Borrowed("scale"),
+                                    ::introspect::metadata::Value::Float(1.0)
+                                ),
+                                (
+                                    ::introspect::borrow::Str::Borrowed("experimental"),
+                                    ::introspect::metadata::Value::Bool(true)
+                                )
+                            ]
+                        }
+                    )
+                )
+            }
+            .to_string()
+        );
+    }
+}
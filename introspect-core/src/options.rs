@@ -0,0 +1,474 @@
+//! Options provided via the `#[introspect(..)]` helper attribute.
+//!
+//! The following options are supported on entities and members:
+//!
+//! * `rename = "..."`: the public name shown in the introspected metadata.
+//! * `hidden`: keeps the entity or member but flags it as hidden.
+//! * `meta(key = value, ..)`: attaches typed, user-defined [`Metadata`], where each
+//!   value is a string, integer, float, or boolean literal.
+//!
+//! Additionally, members (fields and variants) support the following options:
+//!
+//! * `skip`: omits the member from the introspected metadata entirely.
+//!
+//! Finally, structs and enums support the following options:
+//!
+//! * `reflect` (structs only): implements dynamic access to the struct's fields by
+//!   identifier.
+//! * `value`: implements the conversion of an instance into a self-describing value.
+
+use proc_macro2::Span;
+
+use crate::metadata::Value;
+use crate::Metadata;
+
+/// The path of the helper attribute.
+const HELPER: &str = "introspect";
+
+/// The options provided via the `#[introspect(..)]` helper attribute(s) of an
+/// entity or member.
+#[derive(Debug, Default)]
+pub struct Options {
+    /// The location of the `skip` option, if it was provided.
+    skip: Option<Span>,
+
+    /// The public name for the entity or member, if it was provided.
+    rename: Option<String>,
+
+    /// Whether or not the entity or member is hidden.
+    hidden: bool,
+
+    /// The user-defined metadata for the entity or member.
+    metadata: Metadata,
+
+    /// The location of the `reflect` option, if it was provided.
+    reflect: Option<Span>,
+
+    /// The location of the `value` option, if it was provided.
+    value: Option<Span>,
+}
+
+impl Options {
+    /// Gets whether the `skip` option was provided.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let variant: syn::Variant = syn::parse_quote!(#[introspect(skip)] Internal);
+    /// let options = core::Options::try_from(variant.attrs.as_slice())?;
+    ///
+    /// assert!(options.skip());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn skip(&self) -> bool {
+        self.skip.is_some()
+    }
+
+    /// Gets the public name provided via the `rename` option by reference, if it was
+    /// provided.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let variant: syn::Variant = syn::parse_quote!(#[introspect(rename = "Renamed")] Name);
+    /// let options = core::Options::try_from(variant.attrs.as_slice())?;
+    ///
+    /// assert_eq!(options.rename(), Some("Renamed"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn rename(&self) -> Option<&str> {
+        self.rename.as_deref()
+    }
+
+    /// Gets whether the `hidden` option was provided.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let variant: syn::Variant = syn::parse_quote!(#[introspect(hidden)] Internal);
+    /// let options = core::Options::try_from(variant.attrs.as_slice())?;
+    ///
+    /// assert!(options.hidden());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn hidden(&self) -> bool {
+        self.hidden
+    }
+
+    /// Gets the user-defined metadata provided via the `meta(..)` option by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let variant: syn::Variant = syn::parse_quote!(#[introspect(meta(unit = "ms"))] Timeout);
+    /// let options = core::Options::try_from(variant.attrs.as_slice())?;
+    ///
+    /// assert_eq!(options.metadata().get("unit").and_then(|value| value.as_str()), Some("ms"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    /// Gets whether the `reflect` option was provided.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let item: syn::ItemStruct = syn::parse_quote!(#[introspect(reflect)] struct Config;);
+    /// let options = core::Options::try_from(item.attrs.as_slice())?;
+    ///
+    /// assert!(options.reflect());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn reflect(&self) -> bool {
+        self.reflect.is_some()
+    }
+
+    /// Gets whether the `value` option was provided.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let item: syn::ItemEnum = syn::parse_quote!(#[introspect(value)] enum Mode { Fast });
+    /// let options = core::Options::try_from(item.attrs.as_slice())?;
+    ///
+    /// assert!(options.value());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn value(&self) -> bool {
+        self.value.is_some()
+    }
+
+    /// Returns an error if the `skip` option was provided, as entities cannot be
+    /// skipped.
+    pub(crate) fn deny_skip(&self, entity: &str) -> syn::Result<()> {
+        match self.skip {
+            Some(span) => Err(syn::Error::new(
+                span,
+                format!("the `skip` option is not supported on {entity}"),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns an error if the `reflect` option was provided, as only structs can be
+    /// reflected.
+    pub(crate) fn deny_reflect(&self, target: &str) -> syn::Result<()> {
+        match self.reflect {
+            Some(span) => Err(syn::Error::new(
+                span,
+                format!("the `reflect` option is not supported on {target}"),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns an error if the `reflect` or `value` options were provided on a packed
+    /// struct, as references to its fields may be unaligned.
+    pub(crate) fn deny_packed(&self) -> syn::Result<()> {
+        let mut errors: Option<syn::Error> = None;
+
+        for (option, span) in [("reflect", self.reflect), ("value", self.value)] {
+            if let Some(span) = span {
+                let err = syn::Error::new(
+                    span,
+                    format!("`{option}` is not supported on packed structs"),
+                );
+
+                match errors.as_mut() {
+                    Some(errors) => errors.combine(err),
+                    None => errors = Some(err),
+                }
+            }
+        }
+
+        match errors {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns an error if the `value` option was provided, as only structs and
+    /// enums can be converted into values.
+    pub(crate) fn deny_value(&self, target: &str) -> syn::Result<()> {
+        match self.value {
+            Some(span) => Err(syn::Error::new(
+                span,
+                format!("the `value` option is not supported on {target}"),
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+impl TryFrom<&[syn::Attribute]> for Options {
+    type Error = syn::Error;
+
+    fn try_from(value: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut options = Options::default();
+        let mut errors: Option<syn::Error> = None;
+
+        for attr in value.iter().filter(|attr| attr.path().is_ident(HELPER)) {
+            let result = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    options.skip = Some(meta.path.get_ident().unwrap().span());
+                    Ok(())
+                } else if meta.path.is_ident("rename") {
+                    let value = meta.value()?.parse::<syn::LitStr>()?;
+
+                    if options.rename.is_some() {
+                        return Err(meta.error("duplicate `rename` option"));
+                    }
+
+                    options.rename = Some(value.value());
+                    Ok(())
+                } else if meta.path.is_ident("reflect") {
+                    options.reflect = Some(meta.path.get_ident().unwrap().span());
+                    Ok(())
+                } else if meta.path.is_ident("value") {
+                    options.value = Some(meta.path.get_ident().unwrap().span());
+                    Ok(())
+                } else if meta.path.is_ident("hidden") {
+                    options.hidden = true;
+                    Ok(())
+                } else if meta.path.is_ident("meta") {
+                    meta.parse_nested_meta(|entry| {
+                        let key = match entry.path.get_ident() {
+                            Some(ident) => ident.to_string(),
+                            None => return Err(entry.error("metadata keys must be identifiers")),
+                        };
+
+                        if options.metadata.contains_key(&key) {
+                            return Err(entry.error(format!("duplicate metadata key `{key}`")));
+                        }
+
+                        let value = Value::try_from(&entry.value()?.parse::<syn::Expr>()?)?;
+                        options.metadata.insert(key, value);
+                        Ok(())
+                    })
+                } else {
+                    Err(meta.error("unsupported `introspect` option"))
+                }
+            });
+
+            // NOTE: the errors within every helper attribute are combined so that
+            // they can all be reported at once.
+            if let Err(err) = result {
+                match errors.as_mut() {
+                    Some(errors) => errors.combine(err),
+                    None => errors = Some(err),
+                }
+            }
+        }
+
+        match errors {
+            Some(err) => Err(err),
+            None => Ok(options),
+        }
+    }
+}
+
+/// Returns whether the attribute is the `#[introspect(..)]` helper attribute.
+pub(crate) fn is_helper(attr: &syn::Attribute) -> bool {
+    attr.path().is_ident(HELPER)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn options_are_merged_across_attributes() {
+        let variant: syn::Variant = syn::parse_quote! {
+            #[introspect(rename = "Renamed")]
+            #[introspect(hidden)]
+            Name
+        };
+
+        let options = Options::try_from(variant.attrs.as_slice()).unwrap();
+
+        assert!(!options.skip());
+        assert_eq!(options.rename(), Some("Renamed"));
+        assert!(options.hidden());
+    }
+
+    #[test]
+    fn unsupported_options_are_rejected() {
+        let variant: syn::Variant = syn::parse_quote!(
+            #[introspect(unknown)]
+            Name
+        );
+        let err = Options::try_from(variant.attrs.as_slice()).unwrap_err();
+
+        assert_eq!(err.to_string(), "unsupported `introspect` option");
+    }
+
+    #[test]
+    fn rename_requires_a_string_literal() {
+        let variant: syn::Variant = syn::parse_quote!(
+            #[introspect(rename = 1)]
+            Name
+        );
+
+        assert!(Options::try_from(variant.attrs.as_slice()).is_err());
+    }
+
+    #[test]
+    fn rename_cannot_be_repeated() {
+        let variant: syn::Variant = syn::parse_quote! {
+            #[introspect(rename = "First")]
+            #[introspect(rename = "Second")]
+            Name
+        };
+
+        let err = Options::try_from(variant.attrs.as_slice()).unwrap_err();
+        assert_eq!(err.to_string(), "duplicate `rename` option");
+    }
+
+    #[test]
+    fn metadata_keeps_the_type_of_each_value() {
+        let variant: syn::Variant = syn::parse_quote! {
+            #[introspect(meta(unit = "ms", min = 0, max = 65535, scale = 0.5))]
+            #[introspect(meta(experimental = true))]
+            Timeout
+        };
+
+        let options = Options::try_from(variant.attrs.as_slice()).unwrap();
+        let metadata = options.metadata();
+
+        assert_eq!(metadata.len(), 5);
+        assert_eq!(metadata.get("unit"), Some(&Value::from("ms")));
+        assert_eq!(metadata.get("min"), Some(&Value::Int(0)));
+        assert_eq!(metadata.get("max"), Some(&Value::Int(65535)));
+        assert_eq!(metadata.get("scale"), Some(&Value::Float(0.5)));
+        assert_eq!(metadata.get("experimental"), Some(&Value::Bool(true)));
+    }
+
+    #[test]
+    fn metadata_rejects_duplicate_keys_and_unsupported_values() {
+        let variant: syn::Variant = syn::parse_quote!(
+            #[introspect(meta(unit = "ms", unit = "s"))]
+            Timeout
+        );
+        let err = Options::try_from(variant.attrs.as_slice()).unwrap_err();
+        assert_eq!(err.to_string(), "duplicate metadata key `unit`");
+
+        let variant: syn::Variant = syn::parse_quote!(
+            #[introspect(meta(unit = ms))]
+            Timeout
+        );
+        assert!(Options::try_from(variant.attrs.as_slice()).is_err());
+    }
+
+    #[test]
+    fn errors_are_combined_across_attributes() {
+        let variant: syn::Variant = syn::parse_quote! {
+            #[introspect(unknown)]
+            #[introspect(rename = 42)]
+            Name
+        };
+
+        let err = Options::try_from(variant.attrs.as_slice()).unwrap_err();
+        assert_eq!(err.into_iter().count(), 2);
+    }
+
+    #[test]
+    fn reflect_is_only_supported_on_structs() {
+        let variant: syn::Variant = syn::parse_quote!(
+            #[introspect(reflect)]
+            Name
+        );
+
+        let options = Options::try_from(variant.attrs.as_slice()).unwrap();
+        assert!(options.reflect());
+
+        let err = options.deny_reflect("variants").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "the `reflect` option is not supported on variants"
+        );
+    }
+
+    #[test]
+    fn reflect_is_not_supported_on_packed_structs() {
+        let item: syn::ItemStruct = syn::parse_quote!(
+            #[introspect(reflect)]
+            #[repr(C, packed(2))]
+            struct Header {
+                tag: u8,
+                length: u32,
+            }
+        );
+
+        let err = crate::Struct::try_from(&item).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid `introspect` option: `reflect` is not supported on packed structs"
+        );
+    }
+
+    #[test]
+    fn value_is_not_supported_on_packed_structs() {
+        let item: syn::ItemStruct = syn::parse_quote!(
+            #[introspect(value)]
+            #[repr(packed)]
+            struct Header {
+                tag: u8,
+                length: u32,
+            }
+        );
+
+        let err = crate::Struct::try_from(&item).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid `introspect` option: `value` is not supported on packed structs"
+        );
+
+        let item: syn::ItemStruct = syn::parse_quote!(
+            #[introspect(reflect, value)]
+            #[repr(packed)]
+            struct Header {
+                tag: u8,
+                length: u32,
+            }
+        );
+
+        let err = crate::Struct::try_from(&item).unwrap_err();
+        assert_eq!(syn::Error::from(err).into_iter().count(), 2);
+    }
+
+    #[test]
+    fn value_is_not_supported_on_unions() {
+        let item: syn::ItemUnion = syn::parse_quote!(
+            #[introspect(value)]
+            union Bits {
+                int: u32,
+            }
+        );
+
+        let err = crate::Union::try_from(&item).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid `introspect` option: the `value` option is not supported on unions"
+        );
+    }
+}
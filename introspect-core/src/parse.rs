@@ -0,0 +1,656 @@
+//! Helpers shared when parsing entities and members from their syntax trees.
+
+use proc_macro2::Literal;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::r#enum::Variant;
+use crate::r#struct::Field;
+use crate::r#type::option_tokens;
+use crate::r#type::slice_tokens;
+use crate::r#type::str_tokens;
+use crate::Location;
+
+/// A `#[doc = ..]` attribute, which may be nested within `#[cfg_attr(..)]`
+/// attributes.
+struct Doc {
+    /// The predicates of the enclosing `#[cfg_attr(..)]` attributes (if any).
+    predicates: Vec<syn::Meta>,
+
+    /// The value of the attribute.
+    value: syn::Expr,
+}
+
+/// Collects the `#[doc = ..]` attributes, including those that are nested within
+/// `#[cfg_attr(.., doc = ..)]` attributes.
+fn docs(attrs: &[syn::Attribute]) -> Vec<Doc> {
+    let mut docs = Vec::new();
+
+    for attr in attrs {
+        collect_docs(&attr.meta, &[], &mut docs);
+    }
+
+    docs
+}
+
+/// Collects the `#[doc = ..]` attribute(s) within a single attribute's [`syn::Meta`].
+fn collect_docs(meta: &syn::Meta, predicates: &[syn::Meta], docs: &mut Vec<Doc>) {
+    match meta {
+        syn::Meta::NameValue(meta) if meta.path.is_ident("doc") => docs.push(Doc {
+            predicates: predicates.to_vec(),
+            value: meta.value.clone(),
+        }),
+        syn::Meta::List(list) if list.path.is_ident("cfg_attr") => {
+            // NOTE: malformed `cfg_attr` attributes are reported by the compiler.
+            let Ok(metas) = list.parse_args_with(
+                syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated,
+            ) else {
+                return;
+            };
+
+            let mut metas = metas.into_iter();
+            let Some(predicate) = metas.next() else {
+                return;
+            };
+
+            let mut predicates = predicates.to_vec();
+            predicates.push(predicate);
+
+            for meta in metas {
+                collect_docs(&meta, &predicates, docs);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// How a `#[doc = ".."]` attribute was written.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Style {
+    /// A line doc comment (`/// ..`).
+    Line,
+
+    /// A block doc comment (`/** .. */`).
+    Block,
+
+    /// A `#[doc = ".."]` attribute.
+    Attribute,
+}
+
+impl Style {
+    /// Returns whether the attribute was written as a doc comment.
+    fn sugared(self) -> bool {
+        self != Style::Attribute
+    }
+}
+
+impl From<&syn::LitStr> for Style {
+    /// Determines how a documentation string literal was written.
+    ///
+    /// NOTE: the compiler desugars doc comments into `#[doc = ".."]` attributes that
+    /// are spanned by the comment itself. When the source text is unavailable (e.g.,
+    /// outside of a procedural macro), the string literal is assumed to be written
+    /// as an attribute.
+    fn from(lit_str: &syn::LitStr) -> Self {
+        match lit_str.span().source_text() {
+            Some(text) if text.starts_with("//") => Style::Line,
+            Some(text) if text.starts_with("/*") => Style::Block,
+            _ => Style::Attribute,
+        }
+    }
+}
+
+/// Strips the decoration from the value of a block comment (`/** .. */`), the way
+/// rustdoc does.
+///
+/// The first and last lines are removed when they are blank, and the leading `*`
+/// of each line is removed when every line has one. The trailing whitespace of a
+/// single line block comment is removed. Values that were not written as a block
+/// comment are returned as is.
+fn strip_decoration(value: &str, style: Style) -> String {
+    if style != Style::Block {
+        return value.to_string();
+    }
+
+    if !value.contains('\n') {
+        return value.trim_end().to_string();
+    }
+
+    let mut lines = value.split('\n').collect::<Vec<_>>();
+
+    if lines.first().is_some_and(|line| line.trim().is_empty()) {
+        lines.remove(0);
+    }
+
+    if lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+
+    if !lines.is_empty() && lines.iter().all(|line| line.trim_start().starts_with('*')) {
+        for line in lines.iter_mut() {
+            *line = &line.trim_start()[1..];
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// Normalizes the string literals of the documentation attributes the way rustdoc
+/// does: the decoration of block comments is stripped and then the common leading
+/// whitespace of all (non-blank) lines is removed.
+///
+/// As with rustdoc, when doc comments are mixed with `#[doc = ..]` attributes, the
+/// latter are considered to be indented by one more space (to account for the
+/// space that conventionally follows `///`). The expansion of a macro call is
+/// considered to be an unindented `#[doc = ..]` attribute.
+///
+/// Values that are not string literals are normalized to `None`.
+fn normalize(docs: &[Doc]) -> Vec<Option<String>> {
+    let values = docs
+        .iter()
+        .map(|doc| match &doc.value {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(lit_str),
+                ..
+            }) => {
+                let style = Style::from(lit_str);
+                Some((strip_decoration(&lit_str.value(), style), style.sugared()))
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    let macros = docs
+        .iter()
+        .any(|doc| matches!(doc.value, syn::Expr::Macro(_)));
+
+    let mixed = values.iter().flatten().any(|(_, sugared)| *sugared)
+        && (macros || values.iter().flatten().any(|(_, sugared)| !*sugared));
+    let add = |sugared: bool| usize::from(mixed && !sugared);
+
+    let indentation = |line: &str| line.len() - line.trim_start_matches([' ', '\t']).len();
+
+    let common = values
+        .iter()
+        .flatten()
+        .flat_map(|(value, sugared)| value.split('\n').map(|line| (line, *sugared)))
+        .filter(|(line, _)| !line.trim().is_empty())
+        .map(|(line, sugared)| indentation(line) + add(sugared))
+        .chain(macros.then(|| add(false)))
+        .min()
+        .unwrap_or_default();
+
+    values
+        .into_iter()
+        .map(|value| {
+            value.map(|(value, sugared)| {
+                value
+                    .split('\n')
+                    .map(|line| match line.trim().is_empty() {
+                        true => "",
+                        // NOTE: the indentation only consists of (single byte)
+                        // spaces and tabs, so this always slices at a boundary. As
+                        // with rustdoc, an unindented doc comment mixed with
+                        // attributes leaves the common indentation at zero.
+                        false => &line[common.saturating_sub(add(sugared))..],
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+        })
+        .collect()
+}
+
+/// Parses the documentation from the `#[doc = ".."]` attributes, if any exist.
+///
+/// Two forms of the documentation are returned:
+///
+/// * the documentation, which is normalized the way rustdoc does (the decoration
+///   of block comments and the common leading whitespace are stripped, which keeps
+///   the relative indentation of nested lists and code blocks intact), and
+/// * the raw documentation, which is the text of each attribute exactly as written.
+///
+/// Documentation that is provided via a macro call (such as `#[doc =
+/// include_str!("../docs/config.md")]`) or within a `#[cfg_attr(.., doc = ..)]`
+/// attribute cannot be evaluated here, so only the unconditional string literals
+/// are captured (see [`documentation_expression()`] for how the `Introspect` derive
+/// macro handles such documentation).
+///
+/// Rather than stopping at the first unsupported attribute, an error is pushed to
+/// `errors` for every unsupported attribute so they can all be reported at once.
+pub(crate) fn documentation<E>(
+    attrs: &[syn::Attribute],
+    errors: &mut Vec<E>,
+    unsupported_expression: fn(Box<syn::Expr>) -> E,
+    unsupported_expression_literal: fn(Box<syn::ExprLit>) -> E,
+) -> (Option<String>, Option<String>) {
+    let docs = docs(attrs);
+    let normalized = normalize(&docs);
+
+    let mut lines = Vec::new();
+    let mut raw_lines = Vec::new();
+
+    for (doc, normalized) in docs.into_iter().zip(normalized) {
+        match doc.value {
+            syn::Expr::Lit(expr_lit) => match &expr_lit.lit {
+                syn::Lit::Str(lit_str) if doc.predicates.is_empty() => {
+                    lines.extend(normalized);
+                    raw_lines.push(lit_str.value());
+                }
+                syn::Lit::Str(_) => {}
+                _ => errors.push(unsupported_expression_literal(Box::new(expr_lit))),
+            },
+            syn::Expr::Macro(_) => {}
+            expr => errors.push(unsupported_expression(Box::new(expr))),
+        }
+    }
+
+    let join = |lines: Vec<String>| {
+        let lines = lines.join("\n");
+
+        match lines.is_empty() {
+            true => None,
+            false => Some(lines),
+        }
+    };
+
+    (join(lines), join(raw_lines))
+}
+
+/// Generates an expression that evaluates to the documentation from the `#[doc =
+/// ..]` attributes (as an `Option<Str>`), but only if the documentation includes a
+/// macro call (such as `include_str!(..)` or `concat!(..)`) or a `#[cfg_attr(..,
+/// doc = ..)]` attribute.
+///
+/// The `Introspect` derive macro can evaluate neither macro calls nor `cfg`
+/// predicates, so it instead emits each part of the documentation as an element of
+/// a constant array that is joined when compiling the crate that derives
+/// `Introspect`. Parts from `#[cfg_attr(..)]` attributes are placed under the same
+/// `#[cfg(..)]` predicates, so they are only included when the attribute itself
+/// applies. String literals are normalized (as when parsing), whereas the expansion
+/// of each macro call is included verbatim. Each part is separated by a newline,
+/// and documentation that ends up empty evaluates to `None`.
+///
+/// # Examples
+///
+/// ```
+/// use introspect_core as core;
+///
+/// let item: syn::ItemStruct = syn::parse_quote! {
+///     #[doc = "A configuration."]
+///     #[cfg_attr(feature = "std", doc = include_str!("../docs/config.md"))]
+///     struct Config;
+/// };
+///
+/// let expr = core::parse::documentation_expression(&item.attrs).unwrap();
+/// assert_eq!(
+///     expr.to_string(),
+///     quote::quote! {
+///         ::introspect::__private::documentation({
+///             const PARTS: &[&str] = &[
+///                 "A configuration.",
+///                 #[cfg(feature = "std")] include_str!("../docs/config.md")
+///             ];
+///             const BYTES: [u8; ::introspect::__private::joined_len(PARTS)] =
+///                 ::introspect::__private::join(PARTS);
+///             &BYTES
+///         })
+///     }
+///     .to_string()
+/// );
+///
+/// let item: syn::ItemStruct = syn::parse_quote! {
+///     /// A configuration.
+///     struct Config;
+/// };
+///
+/// assert!(core::parse::documentation_expression(&item.attrs).is_none());
+/// ```
+pub fn documentation_expression(attrs: &[syn::Attribute]) -> Option<TokenStream> {
+    expression(attrs, false)
+}
+
+/// Generates an expression that evaluates to the raw documentation from the `#[doc
+/// = ..]` attributes (as an `Option<Str>`), but only if the documentation includes
+/// a macro call or a `#[cfg_attr(.., doc = ..)]` attribute.
+///
+/// This is the same as [`documentation_expression()`] except that string literals
+/// are included exactly as written.
+///
+/// # Examples
+///
+/// ```
+/// use introspect_core as core;
+///
+/// let item: syn::ItemStruct = syn::parse_quote! {
+///     #[doc = "A configuration.  "]
+///     #[doc = include_str!("../docs/config.md")]
+///     struct Config;
+/// };
+///
+/// let expr = core::parse::documentation_raw_expression(&item.attrs).unwrap();
+/// assert_eq!(
+///     expr.to_string(),
+///     quote::quote! {
+///         ::introspect::__private::documentation({
+///             const PARTS: &[&str] = &[
+///                 "A configuration.  ",
+///                 include_str!("../docs/config.md")
+///             ];
+///             const BYTES: [u8; ::introspect::__private::joined_len(PARTS)] =
+///                 ::introspect::__private::join(PARTS);
+///             &BYTES
+///         })
+///     }
+///     .to_string()
+/// );
+/// ```
+pub fn documentation_raw_expression(attrs: &[syn::Attribute]) -> Option<TokenStream> {
+    expression(attrs, true)
+}
+
+/// Generates the expression for [`documentation_expression()`] or (if `raw` is
+/// `true`) [`documentation_raw_expression()`].
+fn expression(attrs: &[syn::Attribute], raw: bool) -> Option<TokenStream> {
+    let docs = docs(attrs);
+
+    if docs
+        .iter()
+        .all(|doc| doc.predicates.is_empty() && !matches!(doc.value, syn::Expr::Macro(_)))
+    {
+        return None;
+    }
+
+    let normalized = normalize(&docs);
+
+    let parts = docs
+        .into_iter()
+        .zip(normalized)
+        .filter_map(|(doc, normalized)| {
+            let value = match (doc.value, normalized) {
+                (
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(lit_str),
+                        ..
+                    }),
+                    Some(normalized),
+                ) => {
+                    let value = match raw {
+                        true => syn::LitStr::new(&lit_str.value(), lit_str.span()),
+                        false => syn::LitStr::new(&normalized, lit_str.span()),
+                    };
+                    quote!(#value)
+                }
+                (syn::Expr::Macro(expr_macro), _) => quote!(#expr_macro),
+                // NOTE: unsupported expressions are reported when parsing.
+                _ => return None,
+            };
+
+            let predicates = doc.predicates;
+            Some(quote!(#(#[cfg(#predicates)])* #value))
+        });
+
+    Some(quote! {
+        ::introspect::__private::documentation({
+            const PARTS: &[&str] = &[#(#parts),*];
+            const BYTES: [u8; ::introspect::__private::joined_len(PARTS)] =
+                ::introspect::__private::join(PARTS);
+            &BYTES
+        })
+    })
+}
+
+/// Expressions for the parts of an entity or member that the `Introspect` derive
+/// macro resolves when compiling the crate that derives `Introspect` rather than
+/// while parsing.
+///
+/// When an entity or member is converted into tokens via its
+/// `to_resolved_tokens()` method, each expression that is provided here replaces
+/// the corresponding part, so every part is passed straight into the `new()`
+/// constructor of the entity or member. Parts that an entity or member does not
+/// have (such as the variants of a struct) are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use introspect_core as core;
+/// use core::parse::Resolved;
+///
+/// let item: syn::ItemStruct = syn::parse_quote! {
+///     /// A configuration.
+///     struct Config;
+/// };
+///
+/// let struct_ = core::Struct::try_from(&item)?;
+/// let resolved = Resolved {
+///     location: Some(quote::quote!(::introspect::Location::new(
+///         ::introspect::borrow::Str::Borrowed(file!()),
+///         ::introspect::borrow::Str::Borrowed(module_path!()),
+///         line!(),
+///         column!(),
+///     ))),
+///     ..Default::default()
+/// };
+///
+/// assert!(struct_
+///     .to_resolved_tokens(&resolved)
+///     .to_string()
+///     .contains("file ! ()"));
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Resolved {
+    /// An expression that evaluates to the [`Location`] of the entity or member.
+    pub location: Option<TokenStream>,
+
+    /// An expression that evaluates to the documentation of the entity or member
+    /// (see [`documentation_expression()`]).
+    pub documentation: Option<TokenStream>,
+
+    /// An expression that evaluates to the raw documentation of the entity or
+    /// member (see [`documentation_raw_expression()`]).
+    pub documentation_raw: Option<TokenStream>,
+
+    /// The tokens of each field of a struct, union, or variant (including the
+    /// `#[cfg(..)]` attributes that each field is gated by).
+    pub fields: Option<Vec<TokenStream>>,
+
+    /// The tokens of each variant of an enum (including the `#[cfg(..)]`
+    /// attributes that each variant is gated by).
+    pub variants: Option<Vec<TokenStream>>,
+
+    /// An expression that evaluates to the discriminant value of a variant (as an
+    /// `i128`).
+    pub discriminant_value: Option<TokenStream>,
+}
+
+impl Resolved {
+    /// Gets the tokens of the location, preferring the resolved expression.
+    pub(crate) fn location_tokens(&self, location: Option<&Location>) -> TokenStream {
+        match &self.location {
+            Some(location) => option_tokens(Some(location)),
+            None => option_tokens(location),
+        }
+    }
+
+    /// Gets the tokens of the documentation, preferring the resolved expression.
+    pub(crate) fn documentation_tokens(&self, documentation: Option<&str>) -> TokenStream {
+        match &self.documentation {
+            Some(documentation) => documentation.clone(),
+            None => option_tokens(documentation.map(str_tokens)),
+        }
+    }
+
+    /// Gets the tokens of the raw documentation, preferring the resolved
+    /// expression.
+    pub(crate) fn documentation_raw_tokens(&self, documentation_raw: Option<&str>) -> TokenStream {
+        match &self.documentation_raw {
+            Some(documentation_raw) => documentation_raw.clone(),
+            None => option_tokens(documentation_raw.map(str_tokens)),
+        }
+    }
+
+    /// Gets the tokens of the fields, preferring the resolved fields.
+    pub(crate) fn fields_tokens(&self, fields: &[Field]) -> TokenStream {
+        match &self.fields {
+            Some(fields) => slice_tokens(fields.iter()),
+            None => slice_tokens(fields.iter()),
+        }
+    }
+
+    /// Gets the tokens of the variants, preferring the resolved variants.
+    pub(crate) fn variants_tokens(&self, variants: &[Variant]) -> TokenStream {
+        match &self.variants {
+            Some(variants) => slice_tokens(variants.iter()),
+            None => slice_tokens(variants.iter()),
+        }
+    }
+
+    /// Gets the tokens of the discriminant value, preferring the resolved
+    /// expression.
+    pub(crate) fn discriminant_value_tokens(
+        &self,
+        discriminant_value: Option<i128>,
+    ) -> TokenStream {
+        match &self.discriminant_value {
+            Some(discriminant_value) => option_tokens(Some(discriminant_value)),
+            None => option_tokens(discriminant_value.map(Literal::i128_unsuffixed)),
+        }
+    }
+}
+
+/// Combines the errors encountered while parsing into a single error, if any exist.
+pub(crate) fn combine<E>(mut errors: Vec<E>, multiple: fn(Vec<E>) -> E) -> Option<E> {
+    match errors.len() {
+        0 => None,
+        1 => errors.pop(),
+        _ => Some(multiple(errors)),
+    }
+}
+
+/// Converts an unsupported documentation expression into a spanned error.
+pub(crate) fn unsupported_expression(expr: &syn::Expr) -> syn::Error {
+    syn::Error::new_spanned(
+        expr,
+        "unsupported doc attribute expression (expected a string literal)",
+    )
+}
+
+/// Converts an unsupported documentation literal into a spanned error.
+pub(crate) fn unsupported_expression_literal(expr_lit: &syn::ExprLit) -> syn::Error {
+    syn::Error::new_spanned(
+        expr_lit,
+        "unsupported doc attribute literal (expected a string literal)",
+    )
+}
+
+/// Combines spanned errors into a single [`syn::Error`] that reports all of them.
+pub(crate) fn into_syn_error<E: Into<syn::Error>>(errors: Vec<E>) -> syn::Error {
+    errors
+        .into_iter()
+        .map(Into::into)
+        .reduce(|mut combined, err| {
+            combined.combine(err);
+            combined
+        })
+        // SAFETY: a [`Multiple`](crate::r#struct::Error::Multiple) error is only
+        // ever constructed from more than one error.
+        .expect("at least one error")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(attrs: &[syn::Attribute]) -> (Option<String>, Option<String>) {
+        let mut errors = Vec::<syn::Error>::new();
+        let documentation = documentation(
+            attrs,
+            &mut errors,
+            |expr| unsupported_expression(&expr),
+            |expr_lit| unsupported_expression_literal(&expr_lit),
+        );
+
+        assert!(errors.is_empty());
+        documentation
+    }
+
+    #[test]
+    fn documentation_is_normalized_the_way_rustdoc_does() {
+        let item: syn::ItemStruct = syn::parse_quote! {
+            #[doc = " A list:"]
+            #[doc = ""]
+            #[doc = " * One."]
+            #[doc = "   * Nested."]
+            #[doc = ""]
+            #[doc = "     let indented = true;"]
+            struct Name;
+        };
+
+        let (documentation, raw) = parse(&item.attrs);
+        assert_eq!(
+            documentation.as_deref(),
+            Some("A list:\n\n* One.\n  * Nested.\n\n    let indented = true;")
+        );
+        assert_eq!(
+            raw.as_deref(),
+            Some(" A list:\n\n * One.\n   * Nested.\n\n     let indented = true;")
+        );
+    }
+
+    #[test]
+    fn attributes_are_not_undecorated() {
+        // NOTE: outside of a procedural macro, the source text is unavailable, so
+        // every string literal is considered to be written as an attribute (block
+        // comments are covered by the tests of the `Introspect` derive macro).
+        let item: syn::ItemStruct = syn::parse_quote! {
+            #[doc = "* One.\n* Two."]
+            struct Name;
+        };
+
+        let (documentation, _) = parse(&item.attrs);
+        assert_eq!(documentation.as_deref(), Some("* One.\n* Two."));
+
+        let item: syn::ItemStruct = syn::parse_quote! {
+            #[doc = "\n    A list:\n\n    * One.\n      * Nested.\n"]
+            struct Name;
+        };
+
+        let (documentation, _) = parse(&item.attrs);
+        assert_eq!(
+            documentation.as_deref(),
+            Some("\nA list:\n\n* One.\n  * Nested.\n")
+        );
+    }
+
+    #[test]
+    fn conditional_documentation_is_evaluated_when_compiling() {
+        let item: syn::ItemStruct = syn::parse_quote! {
+            /// Always.
+            #[cfg_attr(unix, cfg_attr(feature = "std", doc = " Sometimes."))]
+            #[cfg_attr(unix, derive(Debug))]
+            struct Name;
+        };
+
+        let (documentation, _) = parse(&item.attrs);
+        assert_eq!(documentation.as_deref(), Some("Always."));
+
+        let expr = documentation_expression(&item.attrs).unwrap();
+        assert_eq!(
+            expr.to_string(),
+            quote! {
+                ::introspect::__private::documentation({
+                    const PARTS: &[&str] = &[
+                        "Always.",
+                        #[cfg(unix)] #[cfg(feature = "std")] "Sometimes."
+                    ];
+                    const BYTES: [u8; ::introspect::__private::joined_len(PARTS)] =
+                        ::introspect::__private::join(PARTS);
+                    &BYTES
+                })
+            }
+            .to_string()
+        );
+    }
+}
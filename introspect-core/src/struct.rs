@@ -1,33 +1,77 @@
 //! Rust structs.
 
+#[cfg(feature = "alloc")]
 mod builder;
 pub mod field;
 
+#[cfg(feature = "alloc")]
 pub use builder::Builder;
+
+#[cfg(feature = "parse")]
+use proc_macro2::TokenStream;
+#[cfg(feature = "parse")]
+use quote::quote;
+#[cfg(feature = "parse")]
+use quote::ToTokens;
+
+use crate::borrow::Slice;
+use crate::borrow::Str;
+#[cfg(feature = "parse")]
+use crate::parse::Resolved;
+#[cfg(feature = "parse")]
+use crate::r#type::option_tokens;
+#[cfg(feature = "parse")]
+use crate::r#type::slice_tokens;
+#[cfg(feature = "parse")]
+use crate::r#type::str_tokens;
+use crate::Attribute;
+use crate::Documentation;
+use crate::Generics;
+use crate::Location;
+use crate::Metadata;
+#[cfg(feature = "parse")]
+use crate::Options;
+use crate::Visibility;
 pub use field::Field;
 
 /// An error related to a [`Field`].
+#[cfg(feature = "parse")]
 pub enum Error {
     /// Encountered an unsupported expression for a documentation attribute.
-    UnsupportedExpression(syn::Expr),
+    UnsupportedExpression(Box<syn::Expr>),
 
     /// Encountered an unsupported expression literal for a documentation attribute.
-    UnsupportedExpressionLiteral(syn::ExprLit),
+    UnsupportedExpressionLiteral(Box<syn::ExprLit>),
+
+    /// Encountered an invalid option within an `#[introspect(..)]` helper attribute.
+    InvalidOption(syn::Error),
+
+    /// Encountered an error with one of the fields of the struct.
+    Field(field::Error),
+
+    /// Encountered multiple errors (e.g., within several attributes), all of which
+    /// are reported at once.
+    Multiple(Vec<Error>),
 }
 
-impl std::fmt::Debug for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+#[cfg(feature = "parse")]
+impl core::fmt::Debug for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::UnsupportedExpression(_) => f.debug_tuple("UnsupportedExpression").finish(),
             Self::UnsupportedExpressionLiteral(_) => {
                 f.debug_tuple("UnsupportedExpressionLiteral").finish()
             }
+            Self::InvalidOption(err) => f.debug_tuple("InvalidOption").field(err).finish(),
+            Self::Field(err) => f.debug_tuple("Field").field(err).finish(),
+            Self::Multiple(errors) => f.debug_tuple("Multiple").field(errors).finish(),
         }
     }
 }
 
-impl std::fmt::Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+#[cfg(feature = "parse")]
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Error::UnsupportedExpression(_) => {
                 write!(f, "unsupported doc attribute expression")
@@ -35,41 +79,303 @@ impl std::fmt::Display for Error {
             Error::UnsupportedExpressionLiteral(_) => {
                 write!(f, "unsupported doc attribute literal")
             }
+            Error::InvalidOption(err) => write!(f, "invalid `introspect` option: {err}"),
+            Error::Field(err) => write!(f, "field error: {err}"),
+            Error::Multiple(errors) => {
+                for (i, err) in errors.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, "; ")?;
+                    }
+
+                    write!(f, "{err}")?;
+                }
+
+                Ok(())
+            }
         }
     }
 }
 
-impl std::error::Error for Error {}
+#[cfg(feature = "parse")]
+impl core::error::Error for Error {}
+
+#[cfg(feature = "parse")]
+impl From<Error> for syn::Error {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::UnsupportedExpression(expr) => crate::parse::unsupported_expression(&expr),
+            Error::UnsupportedExpressionLiteral(expr_lit) => {
+                crate::parse::unsupported_expression_literal(&expr_lit)
+            }
+            Error::InvalidOption(err) => err,
+            Error::Field(err) => err.into(),
+            Error::Multiple(errors) => crate::parse::into_syn_error(errors),
+        }
+    }
+}
 
 /// A [`Result`](std::result::Result) with an [`Error`].
+#[cfg(feature = "parse")]
 pub type Result<T> = std::result::Result<T, Error>;
 
+/// The shape of a [`Struct`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum StructKind {
+    /// A struct without fields (e.g., `struct Foo;`).
+    #[default]
+    Unit,
+
+    /// A struct with unnamed fields (e.g., `struct Foo(u32);`).
+    Tuple,
+
+    /// A struct with named fields (e.g., `struct Foo { x: i32 }`).
+    Named,
+}
+
+#[cfg(feature = "parse")]
+impl ToTokens for StructKind {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            StructKind::Unit => quote!(::introspect::r#struct::StructKind::Unit),
+            StructKind::Tuple => quote!(::introspect::r#struct::StructKind::Tuple),
+            StructKind::Named => quote!(::introspect::r#struct::StructKind::Named),
+        })
+    }
+}
+
+#[cfg(feature = "parse")]
+impl Struct {
+    /// Converts the [`Struct`] into the tokens that construct it, replacing the
+    /// parts that are provided by `resolved` (see [`Resolved`]).
+    pub fn to_resolved_tokens(&self, resolved: &Resolved) -> TokenStream {
+        let identifier = str_tokens(&self.parts.identifier);
+        let kind = &self.parts.kind;
+        let fields = resolved.fields_tokens(&self.parts.fields);
+        let generics = &self.parts.generics;
+        let visibility = &self.parts.visibility;
+        let attributes = slice_tokens(self.parts.attributes.iter());
+        let rename = option_tokens(self.parts.rename.as_deref().map(str_tokens));
+        let hidden = self.parts.hidden;
+        let metadata = &self.parts.metadata;
+        let location = resolved.location_tokens(self.parts.location.as_ref());
+        let documentation = resolved.documentation_tokens(self.parts.documentation.as_deref());
+        let documentation_raw =
+            resolved.documentation_raw_tokens(self.parts.documentation_raw.as_deref());
+
+        quote! {
+            ::introspect::Struct::new(::introspect::r#struct::Parts {
+                identifier: #identifier,
+                kind: #kind,
+                fields: #fields,
+                generics: #generics,
+                visibility: #visibility,
+                attributes: #attributes,
+                rename: #rename,
+                hidden: #hidden,
+                metadata: #metadata,
+                location: #location,
+                documentation: #documentation,
+                documentation_raw: #documentation_raw,
+            })
+        }
+    }
+}
+
+#[cfg(feature = "parse")]
+impl ToTokens for Struct {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(self.to_resolved_tokens(&Resolved::default()))
+    }
+}
+
+#[cfg(feature = "parse")]
+impl From<&syn::Fields> for StructKind {
+    fn from(value: &syn::Fields) -> Self {
+        match value {
+            syn::Fields::Unit => StructKind::Unit,
+            syn::Fields::Unnamed(_) => StructKind::Tuple,
+            syn::Fields::Named(_) => StructKind::Named,
+        }
+    }
+}
+
+/// The parts of a [`Struct`], which are passed to [`Struct::new()`].
+///
+/// Unlike the builder, the parts can be constructed within a `const` context (as
+/// they are by the `Introspect` derive macro).
+#[derive(Clone, Debug)]
+pub struct Parts {
+    /// The identifier for the struct.
+    pub identifier: Str,
+
+    /// The shape of the struct.
+    pub kind: StructKind,
+
+    /// The fields of the struct.
+    pub fields: Slice<Field>,
+
+    /// The generics of the struct.
+    pub generics: Generics,
+
+    /// The visibility of the struct.
+    pub visibility: Visibility,
+
+    /// The attributes of the struct.
+    pub attributes: Slice<Attribute>,
+
+    /// The public name for the struct, if it was renamed.
+    pub rename: Option<Str>,
+
+    /// Whether or not the struct is hidden.
+    pub hidden: bool,
+
+    /// The user-defined metadata for the struct.
+    pub metadata: Metadata,
+
+    /// The location at which the struct is defined, if it is known.
+    pub location: Option<Location>,
+
+    /// The documentation for the struct, if it exists.
+    pub documentation: Option<Str>,
+
+    /// The raw documentation for the struct, if it exists.
+    pub documentation_raw: Option<Str>,
+}
+
 /// A struct.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Struct {
-    identifier: String,
-
-    documentation: Option<String>,
+    /// The parts of the struct.
+    parts: Parts,
 }
 
 impl Struct {
-    /// Creates a new [`Struct`].
+    /// Creates a new [`Struct`] from its [`Parts`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    /// use core::r#struct::Parts;
+    ///
+    /// let struct_ = core::Struct::new(Parts {
+    ///     identifier: "Name".into(),
+    ///     kind: core::r#struct::StructKind::Named,
+    ///     fields: Vec::new().into(),
+    ///     generics: core::Generics::default(),
+    ///     visibility: core::Visibility::Public,
+    ///     attributes: Vec::new().into(),
+    ///     rename: None,
+    ///     hidden: false,
+    ///     metadata: core::Metadata::default(),
+    ///     location: None,
+    ///     documentation: Some("Documentation.".into()),
+    ///     documentation_raw: Some(" Documentation.".into()),
+    /// });
+    ///
+    /// assert_eq!(struct_.identifier(), "Name");
+    /// ```
+    pub const fn new(parts: Parts) -> Self {
+        Self { parts }
+    }
+
+    /// Consumes `self` to produce a [`Struct`] defined at the provided location.
     ///
     /// # Examples
     ///
     /// ```
     /// use introspect_core as core;
     ///
-    /// let struct_ = core::Struct::new(
-    ///     String::from("Name"),
-    ///     Some(String::from("Documentation."))
+    /// let location = core::Location::new(
+    ///     "src/config.rs".into(),
+    ///     "my_crate::config".into(),
+    ///     42,
+    ///     1
     /// );
+    ///
+    /// let struct_ = core::r#struct::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .try_build()?
+    ///                 .with_location(location);
+    ///
+    /// assert_eq!(struct_.location().map(|location| location.file()), Some("src/config.rs"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn new(identifier: String, documentation: Option<String>) -> Self {
-        Self {
-            identifier,
-            documentation,
-        }
+    pub fn with_location(mut self, location: Location) -> Self {
+        self.parts.location = Some(location);
+        self
+    }
+
+    /// Consumes `self` to produce a [`Struct`] with the provided fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    /// use core::borrow::Slice;
+    ///
+    /// let field = core::r#struct::field::Builder::default()
+    ///                 .ty(core::TypeRef::from(&syn::parse_quote!(u32)))
+    ///                 .try_build()?;
+    ///
+    /// let struct_ = core::r#struct::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .kind(core::r#struct::StructKind::Tuple)
+    ///                 .try_build()?
+    ///                 .with_fields(Slice::from(vec![field]));
+    ///
+    /// assert_eq!(struct_.fields().len(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_fields(mut self, fields: Slice<Field>) -> Self {
+        self.parts.fields = fields;
+        self
+    }
+
+    /// Consumes `self` to produce a [`Struct`] with the provided documentation (or
+    /// without any documentation if `None` is provided).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let struct_ = core::r#struct::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .try_build()?
+    ///                 .with_documentation(Some("Documentation.".into()));
+    ///
+    /// assert_eq!(struct_.documentation(), Some("Documentation."));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_documentation(mut self, documentation: Option<Str>) -> Self {
+        self.parts.documentation = documentation;
+        self
+    }
+
+    /// Consumes `self` to produce a [`Struct`] with the provided raw
+    /// documentation (or without any raw documentation if `None` is provided).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let struct_ = core::r#struct::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .try_build()?
+    ///                 .with_documentation_raw(Some(" Documentation.".into()));
+    ///
+    /// assert_eq!(struct_.documentation_raw(), Some(" Documentation."));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_documentation_raw(mut self, documentation_raw: Option<Str>) -> Self {
+        self.parts.documentation_raw = documentation_raw;
+        self
     }
 
     /// Gets the identifier of the [`Struct`] by reference.
@@ -89,7 +395,222 @@ impl Struct {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn identifier(&self) -> &str {
-        self.identifier.as_str()
+        self.parts.identifier.as_str()
+    }
+
+    /// Gets the shape of the [`Struct`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let struct_ = core::r#struct::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .kind(core::r#struct::StructKind::Tuple)
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(struct_.kind(), core::r#struct::StructKind::Tuple);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn kind(&self) -> StructKind {
+        self.parts.kind
+    }
+
+    /// Gets the fields of the [`Struct`] by reference.
+    ///
+    /// Fields that are marked with `#[introspect(skip)]` are not included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let field = core::r#struct::field::Builder::default()
+    ///                 .identifier("port")
+    ///                 .ty(core::TypeRef::from(&syn::parse_quote!(u16)))
+    ///                 .try_build()?;
+    ///
+    /// let struct_ = core::r#struct::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .field(field)
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(struct_.fields().len(), 1);
+    /// assert_eq!(struct_.fields()[0].identifier(), Some("port"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn fields(&self) -> &[Field] {
+        self.parts.fields.as_slice()
+    }
+
+    /// Gets the generics of the [`Struct`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let struct_ = core::r#struct::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .generics(core::Generics::from(&syn::parse_quote!(<T>)))
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(struct_.generics().params().len(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn generics(&self) -> &Generics {
+        &self.parts.generics
+    }
+
+    /// Gets the public name of the [`Struct`] by reference.
+    ///
+    /// This is the name provided via `#[introspect(rename = "...")]` or, if the struct
+    /// was not renamed, the identifier of the struct.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let struct_ = core::r#struct::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .rename("Renamed")
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(struct_.identifier(), "Name");
+    /// assert_eq!(struct_.name(), "Renamed");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn name(&self) -> &str {
+        self.parts
+            .rename
+            .as_deref()
+            .unwrap_or(self.parts.identifier.as_str())
+    }
+
+    /// Gets whether the [`Struct`] is hidden (via `#[introspect(hidden)]`).
+    ///
+    /// Hidden structs are still introspected, but consumers are expected to leave them
+    /// out of user-facing output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let struct_ = core::r#struct::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .hidden(true)
+    ///                 .try_build()?;
+    ///
+    /// assert!(struct_.is_hidden());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn is_hidden(&self) -> bool {
+        self.parts.hidden
+    }
+
+    /// Gets the user-defined metadata of the [`Struct`] by reference.
+    ///
+    /// This is the metadata provided via `#[introspect(meta(..))]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let struct_ = core::r#struct::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .metadata("unit", "ms")
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(struct_.metadata().get("unit").and_then(|value| value.as_str()), Some("ms"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn metadata(&self) -> &Metadata {
+        &self.parts.metadata
+    }
+
+    /// Gets the location at which the [`Struct`] is defined by reference, if it is
+    /// known.
+    ///
+    /// The `Introspect` derive macro always provides the location.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let location = core::Location::new(
+    ///     "src/config.rs".into(),
+    ///     "my_crate::config".into(),
+    ///     42,
+    ///     1
+    /// );
+    ///
+    /// let struct_ = core::r#struct::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .location(location)
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(struct_.location().map(|location| location.line()), Some(42));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn location(&self) -> Option<&Location> {
+        self.parts.location.as_ref()
+    }
+
+    /// Gets the visibility of the [`Struct`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let struct_ = core::r#struct::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .visibility(core::Visibility::Crate)
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(struct_.visibility(), &core::Visibility::Crate);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn visibility(&self) -> &Visibility {
+        &self.parts.visibility
+    }
+
+    /// Gets the attributes of the [`Struct`] by reference.
+    ///
+    /// Attributes are only captured by the `Introspect` derive macro when the
+    /// `attributes` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let attribute: syn::Attribute = syn::parse_quote!(#[deprecated]);
+    ///
+    /// let struct_ = core::r#struct::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .attribute(core::Attribute::from(&attribute))
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(struct_.attributes()[0].path(), "deprecated");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn attributes(&self) -> &[Attribute] {
+        self.parts.attributes.as_slice()
     }
 
     /// Gets the documentation of the [`Struct`] by reference.
@@ -109,58 +630,152 @@ impl Struct {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn documentation(&self) -> Option<&str> {
-        self.documentation.as_deref()
+        self.parts.documentation.as_deref()
     }
-}
-
-impl std::fmt::Display for Struct {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "::introspect::Struct::new(")?;
-        write!(f, "r#\"{}\"#.into(), ", self.identifier)?;
 
-        match self.documentation.as_ref() {
-            Some(documentation) => write!(f, "Some(r#\"{}\"#.into())", documentation)?,
-            None => write!(f, "None")?,
-        };
+    /// Gets the raw documentation of the [`Struct`] by reference.
+    ///
+    /// Unlike [`Struct::documentation()`], the text of each documentation attribute
+    /// is kept exactly as written: neither the common leading whitespace nor the
+    /// decoration of block comments (`/** .. */`) is stripped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let struct_ = core::r#struct::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .documentation_raw(" Documentation.")
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(struct_.documentation_raw(), Some(" Documentation."));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn documentation_raw(&self) -> Option<&str> {
+        self.parts.documentation_raw.as_deref()
+    }
 
-        write!(f, ")")
+    /// Gets the documentation of the [`Struct`] parsed into a summary, a body, and
+    /// sections (see [`Documentation`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let struct_ = core::r#struct::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .documentation("The summary.\n\nThe body.\n\n# Panics\n\nNever.")
+    ///                 .try_build()?;
+    ///
+    /// let documentation = struct_.documentation_parsed().unwrap();
+    /// assert_eq!(documentation.summary(), "The summary.");
+    /// assert_eq!(documentation.body(), "The body.");
+    /// assert_eq!(documentation.section("Panics").unwrap().content(), "Never.");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn documentation_parsed(&self) -> Option<Documentation<'_>> {
+        self.documentation().map(Documentation::new)
     }
 }
 
+#[cfg(feature = "parse")]
 impl TryFrom<&syn::ItemStruct> for Struct {
     type Error = Error;
 
     fn try_from(value: &syn::ItemStruct) -> Result<Self> {
-        let documentation = value
-            .attrs
+        let mut errors = Vec::new();
+
+        let options = match Options::try_from(value.attrs.as_slice()) {
+            Ok(options) => options,
+            Err(err) => {
+                errors.push(Error::InvalidOption(err));
+                Options::default()
+            }
+        };
+
+        if let Err(err) = options.deny_skip("structs") {
+            errors.push(Error::InvalidOption(err));
+        }
+
+        if packed(&value.attrs) {
+            if let Err(err) = options.deny_packed() {
+                errors.push(Error::InvalidOption(err));
+            }
+        }
+
+        let (documentation, documentation_raw) = crate::parse::documentation(
+            &value.attrs,
+            &mut errors,
+            Error::UnsupportedExpression,
+            Error::UnsupportedExpressionLiteral,
+        );
+
+        let fields = value
+            .fields
             .iter()
-            .filter_map(|attr| match attr.meta.require_name_value() {
-                Ok(v) => Some(v),
-                Err(_) => None,
+            .enumerate()
+            // NOTE: invalid options are reported when the field itself is converted.
+            .filter(|(_, field)| {
+                !Options::try_from(field.attrs.as_slice()).is_ok_and(|options| options.skip())
             })
-            .filter_map(|field| {
-                field
-                    .path
-                    .get_ident()
-                    .map(|ident| (ident, field.value.clone()))
+            .filter_map(|(index, field)| match Field::try_from((index, field)) {
+                Ok(field) => Some(field),
+                Err(err) => {
+                    errors.push(Error::Field(err));
+                    None
+                }
             })
-            .filter(|(ident, _)| *ident == "doc")
-            .map(|(_, expr)| match expr {
-                syn::Expr::Lit(expr_lit) => match expr_lit.lit {
-                    syn::Lit::Str(lit_str) => Ok(lit_str.value().trim().to_string()),
-                    _ => Err(Error::UnsupportedExpressionLiteral(expr_lit)),
-                },
-                _ => Err(Error::UnsupportedExpression(expr)),
-            })
-            .collect::<Result<Vec<String>>>()?
-            .join("\n");
-
-        Ok(Self {
-            identifier: value.ident.to_string(),
-            documentation: match documentation.is_empty() {
-                true => None,
-                false => Some(documentation),
-            },
-        })
+            .collect::<Vec<_>>();
+
+        if let Some(err) = crate::parse::combine(errors, Error::Multiple) {
+            return Err(err);
+        }
+
+        Ok(Self::new(Parts {
+            identifier: value.ident.to_string().into(),
+            kind: StructKind::from(&value.fields),
+            fields: fields.into(),
+            generics: Generics::from(&value.generics),
+            visibility: Visibility::from(&value.vis),
+            attributes: crate::attribute::capture(&value.attrs).into(),
+            rename: options.rename().map(|rename| rename.to_string().into()),
+            hidden: options.hidden(),
+            metadata: options.metadata().clone(),
+            location: None,
+            documentation: documentation.map(Into::into),
+            documentation_raw: documentation_raw.map(Into::into),
+        }))
     }
 }
+
+/// Returns whether the struct has a `#[repr(packed)]` or `#[repr(packed(N))]`
+/// attribute.
+#[cfg(feature = "parse")]
+fn packed(attrs: &[syn::Attribute]) -> bool {
+    let mut packed = false;
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("repr")) {
+        // NOTE: malformed `repr` attributes are reported by the compiler, so
+        // errors when parsing them are ignored here.
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("packed") {
+                packed = true;
+            }
+
+            // NOTE: the arguments of `packed(N)` and `align(N)` are skipped so that
+            // the remaining representations are still visited.
+            if meta.input.peek(syn::token::Paren) {
+                let _content;
+                syn::parenthesized!(_content in meta.input);
+            }
+
+            Ok(())
+        });
+    }
+
+    packed
+}
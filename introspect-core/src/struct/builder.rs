@@ -1,4 +1,16 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::metadata::Value;
+use crate::r#struct::Field;
+use crate::r#struct::Parts;
+use crate::r#struct::StructKind;
+use crate::Attribute;
+use crate::Generics;
+use crate::Location;
+use crate::Metadata;
 use crate::Struct;
+use crate::Visibility;
 
 /// An error related to a [`Builder`].
 #[derive(Debug)]
@@ -7,18 +19,18 @@ pub enum Error {
     MissingIdentifier,
 }
 
-impl std::fmt::Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Error::MissingIdentifier => write!(f, "missing identifier"),
         }
     }
 }
 
-impl std::error::Error for Error {}
+impl core::error::Error for Error {}
 
-/// A [`Result`](std::result::Result) with an [`Error`].
-pub type Result<T> = std::result::Result<T, Error>;
+/// A [`Result`](core::result::Result) with an [`Error`].
+pub type Result<T> = core::result::Result<T, Error>;
 
 /// A builder for a [`Struct`].
 #[derive(Debug, Default)]
@@ -26,8 +38,38 @@ pub struct Builder {
     /// An identifier for the struct.
     identifier: Option<String>,
 
+    /// The shape of the struct.
+    kind: StructKind,
+
+    /// The fields of the struct.
+    fields: Vec<Field>,
+
+    /// The generics for the struct.
+    generics: Generics,
+
+    /// The visibility of the struct.
+    visibility: Visibility,
+
+    /// The attributes of the struct.
+    attributes: Vec<Attribute>,
+
+    /// The public name for the struct, if it was renamed.
+    rename: Option<String>,
+
+    /// Whether or not the struct is hidden.
+    hidden: bool,
+
+    /// The user-defined metadata for the struct.
+    metadata: Metadata,
+
+    /// The location at which the struct is defined, if it is known.
+    location: Option<Location>,
+
     /// The documentation for the struct, if it exists.
     documentation: Option<String>,
+
+    /// The raw documentation for the struct, if it exists.
+    documentation_raw: Option<String>,
 }
 
 impl Builder {
@@ -46,6 +88,157 @@ impl Builder {
         self
     }
 
+    /// Sets the shape for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::r#struct::Builder::default()
+    ///                 .kind(core::r#struct::StructKind::Named);
+    /// ```
+    pub fn kind(mut self, value: StructKind) -> Self {
+        self.kind = value;
+        self
+    }
+
+    /// Adds a field to this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let field = core::r#struct::field::Builder::default()
+    ///                 .identifier("port")
+    ///                 .ty(core::TypeRef::from(&syn::parse_quote!(u16)))
+    ///                 .try_build()?;
+    ///
+    /// let builder = core::r#struct::Builder::default()
+    ///                 .field(field);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn field(mut self, value: Field) -> Self {
+        self.fields.push(value);
+        self
+    }
+
+    /// Sets the generics for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::r#struct::Builder::default()
+    ///                 .generics(core::Generics::from(&syn::parse_quote!(<T>)));
+    /// ```
+    pub fn generics(mut self, value: Generics) -> Self {
+        self.generics = value;
+        self
+    }
+
+    /// Sets the visibility for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::r#struct::Builder::default()
+    ///                 .visibility(core::Visibility::Public);
+    /// ```
+    pub fn visibility(mut self, value: Visibility) -> Self {
+        self.visibility = value;
+        self
+    }
+
+    /// Adds an attribute to this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let attribute: syn::Attribute = syn::parse_quote!(#[deprecated]);
+    ///
+    /// let builder = core::r#struct::Builder::default()
+    ///                 .attribute(core::Attribute::from(&attribute));
+    /// ```
+    pub fn attribute(mut self, value: Attribute) -> Self {
+        self.attributes.push(value);
+        self
+    }
+
+    /// Sets the public name for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::r#struct::Builder::default()
+    ///                 .rename("Renamed");
+    /// ```
+    pub fn rename<S: Into<String>>(mut self, value: S) -> Self {
+        self.rename = Some(value.into());
+        self
+    }
+
+    /// Sets whether the struct is hidden for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::r#struct::Builder::default()
+    ///                 .hidden(true);
+    /// ```
+    pub fn hidden(mut self, value: bool) -> Self {
+        self.hidden = value;
+        self
+    }
+
+    /// Adds a user-defined metadata entry to this [`Builder`].
+    ///
+    /// If the key already exists, its value is replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::r#struct::Builder::default()
+    ///                 .metadata("unit", "ms");
+    /// ```
+    pub fn metadata<K: Into<String>, V: Into<Value>>(mut self, key: K, value: V) -> Self {
+        self.metadata.insert(key, value);
+        self
+    }
+
+    /// Sets the location for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::r#struct::Builder::default()
+    ///                 .location(core::Location::new(
+    ///                     "src/config.rs".into(),
+    ///                     "my_crate::config".into(),
+    ///                     42,
+    ///                     1
+    ///                 ));
+    /// ```
+    pub fn location(mut self, value: Location) -> Self {
+        self.location = Some(value);
+        self
+    }
+
     /// Sets the documentation for this [`Builder`].
     ///
     /// # Examples
@@ -61,6 +254,21 @@ impl Builder {
         self
     }
 
+    /// Sets the raw documentation for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::r#struct::Builder::default()
+    ///                 .documentation_raw(" Documentation.");
+    /// ```
+    pub fn documentation_raw<S: Into<String>>(mut self, value: S) -> Self {
+        self.documentation_raw = Some(value.into());
+        self
+    }
+
     /// Consume `self` to produce an immutable [`Struct`].
     ///
     /// # Examples
@@ -81,9 +289,19 @@ impl Builder {
             None => return Err(Error::MissingIdentifier),
         };
 
-        Ok(Struct {
-            identifier,
-            documentation: self.documentation,
-        })
+        Ok(Struct::new(Parts {
+            identifier: identifier.into(),
+            kind: self.kind,
+            fields: self.fields.into(),
+            generics: self.generics,
+            visibility: self.visibility,
+            attributes: self.attributes.into(),
+            rename: self.rename.map(Into::into),
+            hidden: self.hidden,
+            metadata: self.metadata,
+            location: self.location,
+            documentation: self.documentation.map(Into::into),
+            documentation_raw: self.documentation_raw.map(Into::into),
+        }))
     }
 }
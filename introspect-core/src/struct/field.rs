@@ -1,31 +1,73 @@
 //! Rust struct fields.
 
+#[cfg(feature = "alloc")]
 mod builder;
 
+#[cfg(feature = "alloc")]
 pub use builder::Builder;
 
+#[cfg(feature = "parse")]
+use proc_macro2::Literal;
+#[cfg(feature = "parse")]
+use proc_macro2::TokenStream;
+#[cfg(feature = "parse")]
+use quote::quote;
+#[cfg(feature = "parse")]
+use quote::ToTokens;
+
+use crate::borrow::Slice;
+use crate::borrow::Str;
+#[cfg(feature = "parse")]
+use crate::parse::Resolved;
+#[cfg(feature = "parse")]
+use crate::r#type::option_tokens;
+#[cfg(feature = "parse")]
+use crate::r#type::slice_tokens;
+#[cfg(feature = "parse")]
+use crate::r#type::str_tokens;
+use crate::Attribute;
+use crate::Documentation;
+use crate::Location;
+use crate::Metadata;
+#[cfg(feature = "parse")]
+use crate::Options;
+use crate::TypeRef;
+use crate::Visibility;
+
 /// An error related to a [`Field`].
+#[cfg(feature = "parse")]
 pub enum Error {
     /// Encountered an unsupported expression for a documentation attribute.
-    UnsupportedExpression(syn::Expr),
+    UnsupportedExpression(Box<syn::Expr>),
 
     /// Encountered an unsupported expression literal for a documentation attribute.
-    UnsupportedExpressionLiteral(syn::ExprLit),
+    UnsupportedExpressionLiteral(Box<syn::ExprLit>),
+
+    /// Encountered an invalid option within an `#[introspect(..)]` helper attribute.
+    InvalidOption(syn::Error),
+
+    /// Encountered multiple errors (e.g., within several attributes), all of which
+    /// are reported at once.
+    Multiple(Vec<Error>),
 }
 
-impl std::fmt::Debug for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+#[cfg(feature = "parse")]
+impl core::fmt::Debug for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::UnsupportedExpression(_) => f.debug_tuple("UnsupportedExpression").finish(),
             Self::UnsupportedExpressionLiteral(_) => {
                 f.debug_tuple("UnsupportedExpressionLiteral").finish()
             }
+            Self::InvalidOption(err) => f.debug_tuple("InvalidOption").field(err).finish(),
+            Self::Multiple(errors) => f.debug_tuple("Multiple").field(errors).finish(),
         }
     }
 }
 
-impl std::fmt::Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+#[cfg(feature = "parse")]
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Error::UnsupportedExpression(_) => {
                 write!(f, "unsupported doc attribute expression")
@@ -33,43 +75,189 @@ impl std::fmt::Display for Error {
             Error::UnsupportedExpressionLiteral(_) => {
                 write!(f, "unsupported doc attribute literal")
             }
+            Error::InvalidOption(err) => write!(f, "invalid `introspect` option: {err}"),
+            Error::Multiple(errors) => {
+                for (i, err) in errors.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, "; ")?;
+                    }
+
+                    write!(f, "{err}")?;
+                }
+
+                Ok(())
+            }
         }
     }
 }
 
-impl std::error::Error for Error {}
+#[cfg(feature = "parse")]
+impl core::error::Error for Error {}
+
+#[cfg(feature = "parse")]
+impl From<Error> for syn::Error {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::UnsupportedExpression(expr) => crate::parse::unsupported_expression(&expr),
+            Error::UnsupportedExpressionLiteral(expr_lit) => {
+                crate::parse::unsupported_expression_literal(&expr_lit)
+            }
+            Error::InvalidOption(err) => err,
+            Error::Multiple(errors) => crate::parse::into_syn_error(errors),
+        }
+    }
+}
 
 /// A [`Result`](std::result::Result) with an [`Error`].
+#[cfg(feature = "parse")]
 pub type Result<T> = std::result::Result<T, Error>;
 
-/// A Rust struct field.
-#[derive(Debug)]
-pub struct Field {
-    /// An identifier for the field, if it exists.
-    identifier: Option<String>,
+/// The parts of a [`Field`], which are passed to [`Field::new()`].
+///
+/// Unlike the builder, the parts can be constructed within a `const` context (as
+/// they are by the `Introspect` derive macro).
+#[derive(Clone, Debug)]
+pub struct Parts {
+    /// The identifier for the field, if it exists.
+    pub identifier: Option<Str>,
+
+    /// The zero-based position of the field within its parent.
+    pub index: usize,
+
+    /// The type of the field.
+    pub ty: TypeRef,
+
+    /// The visibility of the field.
+    pub visibility: Visibility,
+
+    /// The attributes of the field.
+    pub attributes: Slice<Attribute>,
+
+    /// The public name for the field, if it was renamed.
+    pub rename: Option<Str>,
+
+    /// Whether or not the field is hidden.
+    pub hidden: bool,
+
+    /// The user-defined metadata for the field.
+    pub metadata: Metadata,
+
+    /// The location at which the field is defined, if it is known.
+    pub location: Option<Location>,
 
     /// The documentation for the field, if it exists.
-    documentation: Option<String>,
+    pub documentation: Option<Str>,
+
+    /// The raw documentation for the field, if it exists.
+    pub documentation_raw: Option<Str>,
+}
+
+/// A Rust struct field.
+#[derive(Clone, Debug)]
+pub struct Field {
+    /// The parts of the field.
+    parts: Parts,
 }
 
 impl Field {
-    /// Creates a new [`Field`].
+    /// Creates a new [`Field`] from its [`Parts`].
     ///
     /// # Examples
     ///
     /// ```
     /// use introspect_core as core;
+    /// use core::r#struct::field::Parts;
+    ///
+    /// let field = core::r#struct::Field::new(Parts {
+    ///     identifier: Some("name".into()),
+    ///     index: 0,
+    ///     ty: core::TypeRef::from(&syn::parse_quote!(u16)),
+    ///     visibility: core::Visibility::Public,
+    ///     attributes: Vec::new().into(),
+    ///     rename: None,
+    ///     hidden: false,
+    ///     metadata: core::Metadata::default(),
+    ///     location: None,
+    ///     documentation: Some("Documentation.".into()),
+    ///     documentation_raw: Some(" Documentation.".into()),
+    /// });
     ///
-    /// let field = core::r#struct::Field::new(
-    ///     Some(String::from("Name")),
-    ///     Some(String::from("Documentation."))
+    /// assert_eq!(field.identifier(), Some("name"));
+    /// ```
+    pub const fn new(parts: Parts) -> Self {
+        Self { parts }
+    }
+
+    /// Consumes `self` to produce a [`Field`] defined at the provided location.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let location = core::Location::new(
+    ///     "src/config.rs".into(),
+    ///     "my_crate::config".into(),
+    ///     42,
+    ///     1
     /// );
+    ///
+    /// let field = core::r#struct::field::Builder::default()
+    ///                 .ty(core::TypeRef::from(&syn::parse_quote!(u16)))
+    ///                 .try_build()?
+    ///                 .with_location(location);
+    ///
+    /// assert_eq!(field.location().map(|location| location.file()), Some("src/config.rs"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn new(identifier: Option<String>, documentation: Option<String>) -> Self {
-        Self {
-            identifier,
-            documentation,
-        }
+    pub fn with_location(mut self, location: Location) -> Self {
+        self.parts.location = Some(location);
+        self
+    }
+
+    /// Consumes `self` to produce a [`Field`] with the provided documentation (or
+    /// without any documentation if `None` is provided).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let field = core::r#struct::field::Builder::default()
+    ///                 .ty(core::TypeRef::from(&syn::parse_quote!(u16)))
+    ///                 .try_build()?
+    ///                 .with_documentation(Some("Documentation.".into()));
+    ///
+    /// assert_eq!(field.documentation(), Some("Documentation."));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_documentation(mut self, documentation: Option<Str>) -> Self {
+        self.parts.documentation = documentation;
+        self
+    }
+
+    /// Consumes `self` to produce a [`Field`] with the provided raw
+    /// documentation (or without any raw documentation if `None` is provided).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let field = core::r#struct::field::Builder::default()
+    ///                 .ty(core::TypeRef::from(&syn::parse_quote!(u16)))
+    ///                 .try_build()?
+    ///                 .with_documentation_raw(Some(" Documentation.".into()));
+    ///
+    /// assert_eq!(field.documentation_raw(), Some(" Documentation."));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_documentation_raw(mut self, documentation_raw: Option<Str>) -> Self {
+        self.parts.documentation_raw = documentation_raw;
+        self
     }
 
     /// Gets the identifier of the [`Field`] by reference.
@@ -81,13 +269,208 @@ impl Field {
     ///
     /// let field = core::r#struct::field::Builder::default()
     ///                 .identifier("Name")
+    ///                 .ty(core::TypeRef::from(&syn::parse_quote!(u16)))
     ///                 .documentation("Documentation.")
-    ///                 .build();
+    ///                 .try_build()?;
     ///
     /// assert_eq!(field.identifier(), Some("Name"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn identifier(&self) -> Option<&str> {
-        self.identifier.as_deref()
+        self.parts.identifier.as_deref()
+    }
+
+    /// Gets the zero-based position of the [`Field`] within its parent.
+    ///
+    /// For fields without an identifier (as found in tuple structs and tuple
+    /// variants), this is the index used to access the field (e.g., `.0`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let field = core::r#struct::field::Builder::default()
+    ///                 .index(1)
+    ///                 .ty(core::TypeRef::from(&syn::parse_quote!(u16)))
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(field.index(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn index(&self) -> usize {
+        self.parts.index
+    }
+
+    /// Gets the type of the [`Field`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let field = core::r#struct::field::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .ty(core::TypeRef::from(&syn::parse_quote!(u16)))
+    ///                 .documentation("Documentation.")
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(field.ty().text(), "u16");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn ty(&self) -> &TypeRef {
+        &self.parts.ty
+    }
+
+    /// Gets the public name of the [`Field`] by reference, if it exists.
+    ///
+    /// This is the name provided via `#[introspect(rename = "...")]` or, if the field
+    /// was not renamed, the identifier of the field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let field = core::r#struct::field::Builder::default()
+    ///                 .identifier("p")
+    ///                 .ty(core::TypeRef::from(&syn::parse_quote!(u16)))
+    ///                 .rename("port")
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(field.identifier(), Some("p"));
+    /// assert_eq!(field.name(), Some("port"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn name(&self) -> Option<&str> {
+        self.parts
+            .rename
+            .as_deref()
+            .or(self.parts.identifier.as_deref())
+    }
+
+    /// Gets whether the [`Field`] is hidden (via `#[introspect(hidden)]`).
+    ///
+    /// Hidden fields are still introspected, but consumers are expected to leave them
+    /// out of user-facing output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let field = core::r#struct::field::Builder::default()
+    ///                 .ty(core::TypeRef::from(&syn::parse_quote!(u16)))
+    ///                 .hidden(true)
+    ///                 .try_build()?;
+    ///
+    /// assert!(field.is_hidden());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn is_hidden(&self) -> bool {
+        self.parts.hidden
+    }
+
+    /// Gets the user-defined metadata of the [`Field`] by reference.
+    ///
+    /// This is the metadata provided via `#[introspect(meta(..))]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let field = core::r#struct::field::Builder::default()
+    ///                 .ty(core::TypeRef::from(&syn::parse_quote!(u16)))
+    ///                 .metadata("unit", "ms")
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(field.metadata().get("unit").and_then(|value| value.as_str()), Some("ms"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn metadata(&self) -> &Metadata {
+        &self.parts.metadata
+    }
+
+    /// Gets the location at which the [`Field`] is defined by reference, if it is
+    /// known.
+    ///
+    /// The `Introspect` derive macro always provides the location.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let location = core::Location::new(
+    ///     "src/config.rs".into(),
+    ///     "my_crate::config".into(),
+    ///     42,
+    ///     1
+    /// );
+    ///
+    /// let field = core::r#struct::field::Builder::default()
+    ///                 .ty(core::TypeRef::from(&syn::parse_quote!(u16)))
+    ///                 .location(location)
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(field.location().map(|location| location.line()), Some(42));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn location(&self) -> Option<&Location> {
+        self.parts.location.as_ref()
+    }
+
+    /// Gets the visibility of the [`Field`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let field = core::r#struct::field::Builder::default()
+    ///                 .ty(core::TypeRef::from(&syn::parse_quote!(u16)))
+    ///                 .visibility(core::Visibility::Crate)
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(field.visibility(), &core::Visibility::Crate);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn visibility(&self) -> &Visibility {
+        &self.parts.visibility
+    }
+
+    /// Gets the attributes of the [`Field`] by reference.
+    ///
+    /// Attributes are only captured by the `Introspect` derive macro when the
+    /// `attributes` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let attribute: syn::Attribute = syn::parse_quote!(#[deprecated]);
+    ///
+    /// let field = core::r#struct::field::Builder::default()
+    ///                 .ty(core::TypeRef::from(&syn::parse_quote!(u16)))
+    ///                 .attribute(core::Attribute::from(&attribute))
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(field.attributes()[0].path(), "deprecated");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn attributes(&self) -> &[Attribute] {
+        self.parts.attributes.as_slice()
     }
 
     /// Gets the documentation of the [`Field`] by reference.
@@ -99,118 +482,249 @@ impl Field {
     ///
     /// let field = core::r#struct::field::Builder::default()
     ///                 .identifier("Name")
+    ///                 .ty(core::TypeRef::from(&syn::parse_quote!(u16)))
     ///                 .documentation("Documentation.")
-    ///                 .build();
+    ///                 .try_build()?;
     ///
     /// assert_eq!(field.documentation(), Some("Documentation."));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn documentation(&self) -> Option<&str> {
-        self.documentation.as_deref()
+        self.parts.documentation.as_deref()
     }
-}
 
-impl std::fmt::Display for Field {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "::introspect::r#struct::Field::new(")?;
+    /// Gets the raw documentation of the [`Field`] by reference.
+    ///
+    /// Unlike [`Field::documentation()`], the text of each documentation attribute
+    /// is kept exactly as written: neither the common leading whitespace nor the
+    /// decoration of block comments (`/** .. */`) is stripped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let field = core::r#struct::field::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .ty(core::TypeRef::from(&syn::parse_quote!(u16)))
+    ///                 .documentation_raw(" Documentation.")
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(field.documentation_raw(), Some(" Documentation."));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn documentation_raw(&self) -> Option<&str> {
+        self.parts.documentation_raw.as_deref()
+    }
 
-        match self.identifier.as_ref() {
-            Some(identifier) => write!(f, "Some(r#\"{}\"#.into())", identifier)?,
-            None => write!(f, "None")?,
-        };
+    /// Gets the documentation of the [`Field`] parsed into a summary, a body, and
+    /// sections (see [`Documentation`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let field = core::r#struct::field::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .ty(core::TypeRef::from(&syn::parse_quote!(u16)))
+    ///                 .documentation("The summary.\n\nThe body.\n\n# Panics\n\nNever.")
+    ///                 .try_build()?;
+    ///
+    /// let documentation = field.documentation_parsed().unwrap();
+    /// assert_eq!(documentation.summary(), "The summary.");
+    /// assert_eq!(documentation.body(), "The body.");
+    /// assert_eq!(documentation.section("Panics").unwrap().content(), "Never.");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn documentation_parsed(&self) -> Option<Documentation<'_>> {
+        self.documentation().map(Documentation::new)
+    }
+}
 
-        write!(f, ", ")?;
+#[cfg(feature = "parse")]
+impl Field {
+    /// Converts the [`Field`] into the tokens that construct it, replacing the
+    /// parts that are provided by `resolved` (see [`Resolved`]).
+    pub fn to_resolved_tokens(&self, resolved: &Resolved) -> TokenStream {
+        let identifier = option_tokens(self.parts.identifier.as_deref().map(str_tokens));
+        let index = Literal::usize_unsuffixed(self.parts.index);
+        let ty = &self.parts.ty;
+        let visibility = &self.parts.visibility;
+        let attributes = slice_tokens(self.parts.attributes.iter());
+        let rename = option_tokens(self.parts.rename.as_deref().map(str_tokens));
+        let hidden = self.parts.hidden;
+        let metadata = &self.parts.metadata;
+        let location = resolved.location_tokens(self.parts.location.as_ref());
+        let documentation = resolved.documentation_tokens(self.parts.documentation.as_deref());
+        let documentation_raw =
+            resolved.documentation_raw_tokens(self.parts.documentation_raw.as_deref());
 
-        match self.documentation.as_ref() {
-            Some(documentation) => write!(f, "Some(r#\"{}\"#.into())", documentation)?,
-            None => write!(f, "None")?,
-        };
+        quote! {
+            ::introspect::r#struct::Field::new(::introspect::r#struct::field::Parts {
+                identifier: #identifier,
+                index: #index,
+                ty: #ty,
+                visibility: #visibility,
+                attributes: #attributes,
+                rename: #rename,
+                hidden: #hidden,
+                metadata: #metadata,
+                location: #location,
+                documentation: #documentation,
+                documentation_raw: #documentation_raw,
+            })
+        }
+    }
+}
 
-        write!(f, ")")
+#[cfg(feature = "parse")]
+impl ToTokens for Field {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(self.to_resolved_tokens(&Resolved::default()))
     }
 }
 
-impl TryFrom<&syn::Field> for Field {
+#[cfg(feature = "parse")]
+impl TryFrom<(usize, &syn::Field)> for Field {
     type Error = Error;
 
-    fn try_from(value: &syn::Field) -> Result<Self> {
-        let documentation = value
-            .attrs
-            .iter()
-            .filter_map(|attr| match attr.meta.require_name_value() {
-                Ok(v) => Some(v),
-                Err(_) => None,
-            })
-            .filter_map(|field| {
-                field
-                    .path
-                    .get_ident()
-                    .map(|ident| (ident, field.value.clone()))
-            })
-            .filter(|(ident, _)| *ident == "doc")
-            .map(|(_, expr)| match expr {
-                syn::Expr::Lit(expr_lit) => match expr_lit.lit {
-                    syn::Lit::Str(lit_str) => Ok(lit_str.value().trim().to_string()),
-                    _ => Err(Error::UnsupportedExpressionLiteral(expr_lit)),
-                },
-                _ => Err(Error::UnsupportedExpression(expr)),
-            })
-            .collect::<Result<Vec<String>>>()?
-            .join("\n");
+    fn try_from((index, value): (usize, &syn::Field)) -> Result<Self> {
+        let mut errors = Vec::new();
+
+        let options = match Options::try_from(value.attrs.as_slice()) {
+            Ok(options) => options,
+            Err(err) => {
+                errors.push(Error::InvalidOption(err));
+                Options::default()
+            }
+        };
+
+        if let Err(err) = options.deny_reflect("fields") {
+            errors.push(Error::InvalidOption(err));
+        }
+
+        if let Err(err) = options.deny_value("fields") {
+            errors.push(Error::InvalidOption(err));
+        }
 
-        Ok(Self {
-            identifier: value.ident.as_ref().map(|ident| ident.to_string()),
-            documentation: match documentation.is_empty() {
-                true => None,
-                false => Some(documentation),
-            },
-        })
+        let (documentation, documentation_raw) = crate::parse::documentation(
+            &value.attrs,
+            &mut errors,
+            Error::UnsupportedExpression,
+            Error::UnsupportedExpressionLiteral,
+        );
+
+        if let Some(err) = crate::parse::combine(errors, Error::Multiple) {
+            return Err(err);
+        }
+
+        Ok(Self::new(Parts {
+            identifier: value.ident.as_ref().map(|ident| ident.to_string().into()),
+            index,
+            ty: TypeRef::from(&value.ty),
+            visibility: Visibility::from(&value.vis),
+            attributes: crate::attribute::capture(&value.attrs).into(),
+            rename: options.rename().map(|rename| rename.to_string().into()),
+            hidden: options.hidden(),
+            metadata: options.metadata().clone(),
+            location: None,
+            documentation: documentation.map(Into::into),
+            documentation_raw: documentation_raw.map(Into::into),
+        }))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Visibility;
 
     #[test]
-    fn display_identifier_and_documentation() {
-        let field = Field::new(
-            Some(String::from("Name")),
-            Some(String::from("Documentation.")),
-        );
+    fn try_from_captures_the_type() {
+        let fields: syn::FieldsNamed = syn::parse_quote!({
+            /// The port.
+            port: u16,
+        });
 
-        assert_eq!(
-            field.to_string(),
-            "::introspect::r#struct::Field::new(Some(r#\"Name\"#.into()), Some(r#\"Documentation.\"#.into()))"
-        )
+        let field = Field::try_from((0, fields.named.first().unwrap())).unwrap();
+
+        assert_eq!(field.identifier(), Some("port"));
+        assert_eq!(field.ty().text(), "u16");
     }
 
     #[test]
-    fn display_only_identifier() {
-        let field = Field::new(Some(String::from("Name")), None);
+    fn try_from_captures_the_visibility() {
+        let fields: syn::FieldsNamed = syn::parse_quote!({
+            pub host: String,
+            pub(crate) port: u16,
+            secret: String,
+        });
 
-        assert_eq!(
-            field.to_string(),
-            "::introspect::r#struct::Field::new(Some(r#\"Name\"#.into()), None)"
-        )
+        let fields = fields
+            .named
+            .iter()
+            .enumerate()
+            .map(|field| Field::try_from(field).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(fields[0].visibility(), &Visibility::Public);
+        assert_eq!(fields[1].visibility(), &Visibility::Crate);
+        assert_eq!(fields[2].visibility(), &Visibility::Private);
     }
 
     #[test]
-    fn display_only_documentation() {
-        let field = Field::new(None, Some(String::from("Documentation.")));
+    fn try_from_captures_the_index() {
+        let fields: syn::FieldsUnnamed = syn::parse_quote!((u8, String));
 
-        assert_eq!(
-            field.to_string(),
-            "::introspect::r#struct::Field::new(None, Some(r#\"Documentation.\"#.into()))"
-        )
+        let fields = fields
+            .unnamed
+            .iter()
+            .enumerate()
+            .map(|field| Field::try_from(field).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(fields[0].identifier(), None);
+        assert_eq!(fields[0].index(), 0);
+        assert_eq!(fields[1].identifier(), None);
+        assert_eq!(fields[1].index(), 1);
+        assert_eq!(fields[1].ty().text(), "String");
     }
 
     #[test]
-    fn display_neither() {
-        let field = Field::new(None, None);
+    fn try_from_captures_the_options() {
+        let fields: syn::FieldsNamed = syn::parse_quote!({
+            #[introspect(rename = "port", hidden, meta(min = 1, max = 65535))]
+            p: u16,
+        });
 
+        let field = Field::try_from((0, fields.named.first().unwrap())).unwrap();
+
+        assert_eq!(field.identifier(), Some("p"));
+        assert_eq!(field.name(), Some("port"));
+        assert!(field.is_hidden());
         assert_eq!(
-            field.to_string(),
-            "::introspect::r#struct::Field::new(None, None)"
-        )
+            field.metadata().get("min").and_then(|value| value.as_int()),
+            Some(1)
+        );
+        assert_eq!(
+            field.metadata().get("max").and_then(|value| value.as_int()),
+            Some(65535)
+        );
+    }
+
+    #[test]
+    fn try_from_rejects_invalid_options() {
+        let fields: syn::FieldsNamed = syn::parse_quote!({
+            #[introspect(unknown)]
+            p: u16,
+        });
+
+        let err = Field::try_from((0, fields.named.first().unwrap())).unwrap_err();
+
+        assert!(matches!(err, Error::InvalidOption(_)));
     }
 }
@@ -1,4 +1,34 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::metadata::Value;
+use crate::r#struct::field::Parts;
 use crate::r#struct::Field;
+use crate::Attribute;
+use crate::Location;
+use crate::Metadata;
+use crate::TypeRef;
+use crate::Visibility;
+
+/// An error related to a [`Builder`].
+#[derive(Debug)]
+pub enum Error {
+    /// A type was never added to the [`Builder`].
+    MissingType,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::MissingType => write!(f, "missing type"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+/// A [`Result`](core::result::Result) with an [`Error`].
+pub type Result<T> = core::result::Result<T, Error>;
 
 /// A builder for a [`Field`].
 #[derive(Debug, Default)]
@@ -6,8 +36,35 @@ pub struct Builder {
     /// An identifier for the field, if it exists.
     identifier: Option<String>,
 
+    /// The zero-based position of the field within its parent.
+    index: usize,
+
+    /// The type of the field.
+    ty: Option<TypeRef>,
+
+    /// The visibility of the field.
+    visibility: Visibility,
+
+    /// The attributes of the field.
+    attributes: Vec<Attribute>,
+
+    /// The public name for the field, if it was renamed.
+    rename: Option<String>,
+
+    /// Whether or not the field is hidden.
+    hidden: bool,
+
+    /// The user-defined metadata for the field.
+    metadata: Metadata,
+
+    /// The location at which the field is defined, if it is known.
+    location: Option<Location>,
+
     /// The documentation for the field, if it exists.
     documentation: Option<String>,
+
+    /// The raw documentation for the field, if it exists.
+    documentation_raw: Option<String>,
 }
 
 impl Builder {
@@ -26,6 +83,135 @@ impl Builder {
         self
     }
 
+    /// Sets the zero-based position for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::r#struct::field::Builder::default()
+    ///                 .index(0);
+    /// ```
+    pub fn index(mut self, value: usize) -> Self {
+        self.index = value;
+        self
+    }
+
+    /// Sets the type for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::r#struct::field::Builder::default()
+    ///                 .ty(core::TypeRef::from(&syn::parse_quote!(u16)));
+    /// ```
+    pub fn ty(mut self, value: TypeRef) -> Self {
+        self.ty = Some(value);
+        self
+    }
+
+    /// Sets the visibility for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::r#struct::field::Builder::default()
+    ///                 .visibility(core::Visibility::Public);
+    /// ```
+    pub fn visibility(mut self, value: Visibility) -> Self {
+        self.visibility = value;
+        self
+    }
+
+    /// Adds an attribute to this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let attribute: syn::Attribute = syn::parse_quote!(#[deprecated]);
+    ///
+    /// let builder = core::r#struct::field::Builder::default()
+    ///                 .attribute(core::Attribute::from(&attribute));
+    /// ```
+    pub fn attribute(mut self, value: Attribute) -> Self {
+        self.attributes.push(value);
+        self
+    }
+
+    /// Sets the public name for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::r#struct::field::Builder::default()
+    ///                 .rename("Renamed");
+    /// ```
+    pub fn rename<S: Into<String>>(mut self, value: S) -> Self {
+        self.rename = Some(value.into());
+        self
+    }
+
+    /// Sets whether the field is hidden for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::r#struct::field::Builder::default()
+    ///                 .hidden(true);
+    /// ```
+    pub fn hidden(mut self, value: bool) -> Self {
+        self.hidden = value;
+        self
+    }
+
+    /// Adds a user-defined metadata entry to this [`Builder`].
+    ///
+    /// If the key already exists, its value is replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::r#struct::field::Builder::default()
+    ///                 .metadata("unit", "ms");
+    /// ```
+    pub fn metadata<K: Into<String>, V: Into<Value>>(mut self, key: K, value: V) -> Self {
+        self.metadata.insert(key, value);
+        self
+    }
+
+    /// Sets the location for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::r#struct::field::Builder::default()
+    ///                 .location(core::Location::new(
+    ///                     "src/config.rs".into(),
+    ///                     "my_crate::config".into(),
+    ///                     42,
+    ///                     1
+    ///                 ));
+    /// ```
+    pub fn location(mut self, value: Location) -> Self {
+        self.location = Some(value);
+        self
+    }
+
     /// Sets the documentation for this [`Builder`].
     ///
     /// # Examples
@@ -41,8 +227,25 @@ impl Builder {
         self
     }
 
+    /// Sets the raw documentation for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::r#struct::field::Builder::default()
+    ///                 .documentation_raw(" Documentation.");
+    /// ```
+    pub fn documentation_raw<S: Into<String>>(mut self, value: S) -> Self {
+        self.documentation_raw = Some(value.into());
+        self
+    }
+
     /// Consume `self` to produce an immutable [`Field`].
     ///
+    /// This replaces the infallible `build()`, as a [`Field`] now requires a type.
+    ///
     /// # Examples
     ///
     /// ```
@@ -50,13 +253,30 @@ impl Builder {
     ///
     /// let field = core::r#struct::field::Builder::default()
     ///                 .identifier("Name")
+    ///                 .ty(core::TypeRef::from(&syn::parse_quote!(u16)))
     ///                 .documentation("Documentation.")
-    ///                 .build();
+    ///                 .try_build()?;
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn build(self) -> Field {
-        Field {
-            identifier: self.identifier,
-            documentation: self.documentation,
-        }
+    pub fn try_build(self) -> Result<Field> {
+        let ty = match self.ty {
+            Some(ty) => ty,
+            None => return Err(Error::MissingType),
+        };
+
+        Ok(Field::new(Parts {
+            identifier: self.identifier.map(Into::into),
+            index: self.index,
+            ty,
+            visibility: self.visibility,
+            attributes: self.attributes.into(),
+            rename: self.rename.map(Into::into),
+            hidden: self.hidden,
+            metadata: self.metadata,
+            location: self.location,
+            documentation: self.documentation.map(Into::into),
+            documentation_raw: self.documentation_raw.map(Into::into),
+        }))
     }
 }
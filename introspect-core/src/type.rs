@@ -0,0 +1,648 @@
+//! Rust types.
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+#[cfg(feature = "parse")]
+use proc_macro2::Span;
+#[cfg(feature = "parse")]
+use proc_macro2::TokenStream;
+#[cfg(feature = "parse")]
+use quote::quote;
+#[cfg(feature = "parse")]
+use quote::ToTokens;
+
+use crate::borrow::Slice;
+use crate::borrow::Str;
+
+/// A reference to a Rust type as it was written in the source code.
+#[derive(Clone, Debug)]
+pub struct TypeRef {
+    /// The normalized source text of the type.
+    text: Str,
+
+    /// The structure of the type.
+    kind: Kind,
+}
+
+/// The structure of a [`TypeRef`].
+#[derive(Clone, Debug)]
+pub enum Kind {
+    /// A path to a type, such as `u16` or `std::collections::HashMap<K, V>`.
+    Path(Slice<PathSegment>),
+
+    /// A reference, such as `&'a str` or `&mut T`.
+    Reference {
+        /// The lifetime of the reference, if it exists.
+        lifetime: Option<Str>,
+
+        /// Whether or not the reference is mutable.
+        mutable: bool,
+
+        /// The referenced type.
+        elem: Nested,
+    },
+
+    /// A fixed size array, such as `[u8; 4]`.
+    Array {
+        /// The type of the elements within the array.
+        elem: Nested,
+
+        /// The normalized source text of the length expression.
+        length: Str,
+    },
+
+    /// A dynamically sized slice, such as `[u8]`.
+    Slice(Nested),
+
+    /// A tuple, such as `(u8, String)` or the unit type `()`.
+    Tuple(Slice<TypeRef>),
+
+    /// Any other type (function pointers, trait objects, raw pointers, etc.).
+    ///
+    /// Only the normalized source text on the containing [`TypeRef`] is available.
+    Other,
+}
+
+/// A [`TypeRef`] nested within a [`Kind`], such as the referenced type of a
+/// reference.
+///
+/// Nested types are borrowed for the `'static` lifetime when generated by the
+/// `Introspect` derive macro and are otherwise owned. Either way, the nested
+/// [`TypeRef`] is accessible via [`Deref`](core::ops::Deref).
+#[derive(Clone, Debug)]
+pub enum Nested {
+    /// A nested type that is borrowed for the `'static` lifetime.
+    Borrowed(&'static TypeRef),
+
+    /// A nested type that is owned.
+    #[cfg(feature = "alloc")]
+    Owned(Box<TypeRef>),
+}
+
+/// A segment of a [`Kind::Path`].
+#[derive(Clone, Debug)]
+pub struct PathSegment {
+    /// The identifier for the segment.
+    identifier: Str,
+
+    /// The generic arguments for the segment.
+    arguments: Slice<GenericArgument>,
+}
+
+/// A generic argument within a [`PathSegment`].
+#[derive(Clone, Debug)]
+pub enum GenericArgument {
+    /// A lifetime argument, such as `'a`.
+    Lifetime(Str),
+
+    /// A type argument, such as `u8` in `Vec<u8>`.
+    Type(TypeRef),
+
+    /// A const argument, stored as normalized source text.
+    Const(Str),
+
+    /// Any other argument (associated types, constraints, parenthesized arguments,
+    /// etc.), stored as normalized source text.
+    Other(Str),
+}
+
+impl TypeRef {
+    /// Creates a new [`TypeRef`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    /// use core::borrow::Slice;
+    /// use core::borrow::Str;
+    ///
+    /// let ty = core::TypeRef::new(
+    ///     Str::Borrowed("()"),
+    ///     core::r#type::Kind::Tuple(Slice::Borrowed(&[]))
+    /// );
+    /// ```
+    pub const fn new(text: Str, kind: Kind) -> Self {
+        Self { text, kind }
+    }
+
+    /// Gets the normalized source text of the [`TypeRef`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let ty = core::TypeRef::from(&syn::parse_quote!(std::vec::Vec < u8 >));
+    /// assert_eq!(ty.text(), "std::vec::Vec<u8>");
+    /// ```
+    pub fn text(&self) -> &str {
+        self.text.as_str()
+    }
+
+    /// Gets the structure of the [`TypeRef`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    /// use core::r#type::Kind;
+    ///
+    /// let ty = core::TypeRef::from(&syn::parse_quote!(&'a mut str));
+    ///
+    /// match ty.kind() {
+    ///     Kind::Reference { lifetime, mutable, elem } => {
+    ///         assert_eq!(lifetime.as_deref(), Some("'a"));
+    ///         assert!(mutable);
+    ///         assert_eq!(elem.text(), "str");
+    ///     }
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    pub fn kind(&self) -> &Kind {
+        &self.kind
+    }
+}
+
+impl core::ops::Deref for Nested {
+    type Target = TypeRef;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Nested::Borrowed(ty) => ty,
+            #[cfg(feature = "alloc")]
+            Nested::Owned(ty) => ty,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<TypeRef> for Nested {
+    fn from(value: TypeRef) -> Self {
+        Nested::Owned(Box::new(value))
+    }
+}
+
+impl PathSegment {
+    /// Creates a new [`PathSegment`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    /// use core::borrow::Slice;
+    /// use core::borrow::Str;
+    ///
+    /// let segment = core::r#type::PathSegment::new(Str::Borrowed("u16"), Slice::Borrowed(&[]));
+    /// ```
+    pub const fn new(identifier: Str, arguments: Slice<GenericArgument>) -> Self {
+        Self {
+            identifier,
+            arguments,
+        }
+    }
+
+    /// Gets the identifier of the [`PathSegment`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    /// use core::borrow::Slice;
+    /// use core::borrow::Str;
+    ///
+    /// let segment = core::r#type::PathSegment::new(Str::Borrowed("u16"), Slice::Borrowed(&[]));
+    /// assert_eq!(segment.identifier(), "u16");
+    /// ```
+    pub fn identifier(&self) -> &str {
+        self.identifier.as_str()
+    }
+
+    /// Gets the generic arguments of the [`PathSegment`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    /// use core::borrow::Slice;
+    /// use core::borrow::Str;
+    ///
+    /// let segment = core::r#type::PathSegment::new(Str::Borrowed("u16"), Slice::Borrowed(&[]));
+    /// assert!(segment.arguments().is_empty());
+    /// ```
+    pub fn arguments(&self) -> &[GenericArgument] {
+        self.arguments.as_slice()
+    }
+}
+
+#[cfg(feature = "parse")]
+impl ToTokens for TypeRef {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let text = str_tokens(&self.text);
+        let kind = &self.kind;
+
+        tokens.extend(quote!(::introspect::r#type::TypeRef::new(#text, #kind)))
+    }
+}
+
+#[cfg(feature = "parse")]
+impl ToTokens for Kind {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Kind::Path(segments) => {
+                let segments = slice_tokens(segments.iter());
+                quote!(::introspect::r#type::Kind::Path(#segments))
+            }
+            Kind::Reference {
+                lifetime,
+                mutable,
+                elem,
+            } => {
+                let lifetime = option_tokens(lifetime.as_deref().map(str_tokens));
+                quote! {
+                    ::introspect::r#type::Kind::Reference {
+                        lifetime: #lifetime,
+                        mutable: #mutable,
+                        elem: #elem,
+                    }
+                }
+            }
+            Kind::Array { elem, length } => {
+                let length = str_tokens(length);
+                quote!(::introspect::r#type::Kind::Array { elem: #elem, length: #length })
+            }
+            Kind::Slice(elem) => quote!(::introspect::r#type::Kind::Slice(#elem)),
+            Kind::Tuple(elems) => {
+                let elems = slice_tokens(elems.iter());
+                quote!(::introspect::r#type::Kind::Tuple(#elems))
+            }
+            Kind::Other => quote!(::introspect::r#type::Kind::Other),
+        })
+    }
+}
+
+#[cfg(feature = "parse")]
+impl ToTokens for Nested {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let inner: &TypeRef = self;
+        tokens.extend(quote!(::introspect::r#type::Nested::Borrowed(const { &#inner })))
+    }
+}
+
+#[cfg(feature = "parse")]
+impl ToTokens for PathSegment {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let identifier = str_tokens(&self.identifier);
+        let arguments = slice_tokens(self.arguments.iter());
+
+        tokens.extend(quote!(::introspect::r#type::PathSegment::new(#identifier, #arguments)))
+    }
+}
+
+#[cfg(feature = "parse")]
+impl ToTokens for GenericArgument {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            GenericArgument::Type(ty) => quote!(::introspect::r#type::GenericArgument::Type(#ty)),
+            GenericArgument::Lifetime(lifetime) => {
+                let lifetime = str_tokens(lifetime);
+                quote!(::introspect::r#type::GenericArgument::Lifetime(#lifetime))
+            }
+            GenericArgument::Const(expr) => {
+                let expr = str_tokens(expr);
+                quote!(::introspect::r#type::GenericArgument::Const(#expr))
+            }
+            GenericArgument::Other(text) => {
+                let text = str_tokens(text);
+                quote!(::introspect::r#type::GenericArgument::Other(#text))
+            }
+        })
+    }
+}
+
+/// Converts a string into the tokens of a [`Str`] that is borrowed for the
+/// `'static` lifetime.
+///
+/// The string is emitted as a [`syn::LitStr`], which escapes its contents, so any
+/// string (e.g., documentation containing quotes or hashes) round-trips exactly.
+#[cfg(feature = "parse")]
+pub(crate) fn str_tokens(value: &str) -> TokenStream {
+    let value = syn::LitStr::new(value, Span::call_site());
+    quote!(::introspect::borrow::Str::Borrowed(#value))
+}
+
+/// Converts an optional value into the tokens of an [`Option`].
+#[cfg(feature = "parse")]
+pub(crate) fn option_tokens<T: ToTokens>(value: Option<T>) -> TokenStream {
+    match value {
+        Some(value) => quote!(::core::option::Option::Some(#value)),
+        None => quote!(::core::option::Option::None),
+    }
+}
+
+/// Converts items into the tokens of a [`Slice`] that is borrowed for the
+/// `'static` lifetime.
+///
+/// The slice is wrapped within an inline `const` block, as temporaries passed to a
+/// function are otherwise not extended to the `'static` lifetime within constants.
+#[cfg(feature = "parse")]
+pub(crate) fn slice_tokens<T: ToTokens>(items: impl Iterator<Item = T>) -> TokenStream {
+    quote!(::introspect::borrow::Slice::Borrowed(const { &[#(#items),*] }))
+}
+
+#[cfg(feature = "parse")]
+impl From<&syn::Type> for TypeRef {
+    fn from(value: &syn::Type) -> Self {
+        let kind = match value {
+            syn::Type::Group(group) => return TypeRef::from(group.elem.as_ref()),
+            syn::Type::Paren(paren) => return TypeRef::from(paren.elem.as_ref()),
+            syn::Type::Path(path) if path.qself.is_none() => {
+                Kind::Path(path.path.segments.iter().map(PathSegment::from).collect())
+            }
+            syn::Type::Reference(reference) => Kind::Reference {
+                lifetime: reference
+                    .lifetime
+                    .as_ref()
+                    .map(|lifetime| lifetime.to_string().into()),
+                mutable: reference.mutability.is_some(),
+                elem: TypeRef::from(reference.elem.as_ref()).into(),
+            },
+            syn::Type::Array(array) => Kind::Array {
+                elem: TypeRef::from(array.elem.as_ref()).into(),
+                length: normalize(&array.len).into(),
+            },
+            syn::Type::Slice(slice) => Kind::Slice(TypeRef::from(slice.elem.as_ref()).into()),
+            syn::Type::Tuple(tuple) => Kind::Tuple(tuple.elems.iter().map(TypeRef::from).collect()),
+            _ => Kind::Other,
+        };
+
+        Self {
+            text: normalize(value).into(),
+            kind,
+        }
+    }
+}
+
+#[cfg(feature = "parse")]
+impl From<&syn::PathSegment> for PathSegment {
+    fn from(value: &syn::PathSegment) -> Self {
+        let arguments = match &value.arguments {
+            syn::PathArguments::None => Slice::Borrowed(&[]),
+            syn::PathArguments::AngleBracketed(arguments) => arguments
+                .args
+                .iter()
+                .map(|argument| match argument {
+                    syn::GenericArgument::Lifetime(lifetime) => {
+                        GenericArgument::Lifetime(lifetime.to_string().into())
+                    }
+                    syn::GenericArgument::Type(ty) => GenericArgument::Type(TypeRef::from(ty)),
+                    syn::GenericArgument::Const(expr) => {
+                        GenericArgument::Const(normalize(expr).into())
+                    }
+                    other => GenericArgument::Other(normalize(other).into()),
+                })
+                .collect(),
+            syn::PathArguments::Parenthesized(arguments) => {
+                Slice::Owned(vec![GenericArgument::Other(normalize(arguments).into())])
+            }
+        };
+
+        Self {
+            identifier: value.ident.to_string().into(),
+            arguments,
+        }
+    }
+}
+
+/// Renders a syntax node as compact source text (e.g., `Vec<u8>` instead of the
+/// `Vec < u8 >` produced by printing the raw token stream).
+#[cfg(feature = "parse")]
+pub(crate) fn normalize<T: quote::ToTokens>(node: &T) -> String {
+    let mut result = String::new();
+    write_tokens(&mut result, node.to_token_stream());
+    result
+}
+
+/// The previously written token, as needed to decide on the whitespace that comes
+/// before the next token.
+#[cfg(feature = "parse")]
+#[derive(Clone, Copy)]
+enum Previous {
+    /// An identifier (`lifetime` is set for the identifier part of `'a`).
+    Ident { lifetime: bool, keyword: bool },
+
+    /// A punctuation character (`after` is the joint character preceding it, if any,
+    /// and `unary` is set for a leading `-` or `!` operator).
+    Punct {
+        ch: char,
+        joint: bool,
+        after: Option<char>,
+        unary: bool,
+    },
+
+    /// A literal or a group.
+    Other,
+}
+
+#[cfg(feature = "parse")]
+fn write_tokens(result: &mut String, stream: proc_macro2::TokenStream) {
+    use proc_macro2::Delimiter;
+    use proc_macro2::Spacing;
+    use proc_macro2::TokenTree;
+
+    let mut previous: Option<Previous> = None;
+
+    for token in stream {
+        if let Some(previous) = previous {
+            if needs_space(previous, &token) {
+                result.push(' ');
+            }
+        }
+
+        previous = Some(match &token {
+            TokenTree::Group(group) => {
+                let (open, close) = match group.delimiter() {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Bracket => ("[", "]"),
+                    Delimiter::Brace => ("{", "}"),
+                    Delimiter::None => ("", ""),
+                };
+
+                result.push_str(open);
+                write_tokens(result, group.stream());
+                result.push_str(close);
+
+                Previous::Other
+            }
+            TokenTree::Ident(ident) => {
+                let ident = ident.to_string();
+                result.push_str(&ident);
+
+                Previous::Ident {
+                    lifetime: matches!(
+                        previous,
+                        Some(Previous::Punct {
+                            ch: '\'',
+                            joint: true,
+                            ..
+                        })
+                    ),
+                    keyword: matches!(ident.as_str(), "as" | "const" | "dyn" | "impl" | "mut"),
+                }
+            }
+            TokenTree::Punct(punct) => {
+                result.push(punct.as_char());
+
+                Previous::Punct {
+                    ch: punct.as_char(),
+                    joint: punct.spacing() == Spacing::Joint,
+                    after: match previous {
+                        Some(Previous::Punct {
+                            ch, joint: true, ..
+                        }) => Some(ch),
+                        _ => None,
+                    },
+                    unary: matches!(punct.as_char(), '-' | '!')
+                        && matches!(previous, None | Some(Previous::Punct { .. })),
+                }
+            }
+            TokenTree::Literal(literal) => {
+                result.push_str(&literal.to_string());
+                Previous::Other
+            }
+        });
+    }
+}
+
+#[cfg(feature = "parse")]
+fn needs_space(previous: Previous, next: &proc_macro2::TokenTree) -> bool {
+    use proc_macro2::Delimiter;
+    use proc_macro2::TokenTree;
+
+    let next_punct = match next {
+        TokenTree::Punct(punct) => Some(punct.as_char()),
+        _ => None,
+    };
+
+    match previous {
+        // Multi-character punctuation (`::`, `->`, `'a`) is never split.
+        Previous::Punct { joint: true, .. } | Previous::Punct { unary: true, .. } => false,
+        _ if matches!(next_punct, Some(',' | ';' | ':' | '>')) => false,
+        Previous::Punct {
+            ch: '&' | '*' | '<' | '?',
+            after: None,
+            ..
+        } => false,
+        Previous::Punct {
+            ch: ':',
+            after: Some(':'),
+            ..
+        } => false,
+        Previous::Ident { .. } if next_punct == Some('<') => false,
+        Previous::Ident {
+            lifetime: false,
+            keyword: false,
+        } => {
+            !matches!(next, TokenTree::Group(group) if group.delimiter() == Delimiter::Parenthesis)
+        }
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(ty: syn::Type) -> String {
+        TypeRef::from(&ty).text().to_string()
+    }
+
+    #[test]
+    fn normalizes_source_text() {
+        assert_eq!(text(syn::parse_quote!(u16)), "u16");
+        assert_eq!(text(syn::parse_quote!(Vec<Vec<u8>>)), "Vec<Vec<u8>>");
+        assert_eq!(text(syn::parse_quote!(&'a mut str)), "&'a mut str");
+        assert_eq!(text(syn::parse_quote!([u8; 4])), "[u8; 4]");
+        assert_eq!(text(syn::parse_quote!((u8, String))), "(u8, String)");
+        assert_eq!(
+            text(syn::parse_quote!(std::collections::HashMap<String, u32>)),
+            "std::collections::HashMap<String, u32>"
+        );
+        assert_eq!(
+            text(syn::parse_quote!(Box<dyn Fn(u8) -> u8 + Send>)),
+            "Box<dyn Fn(u8) -> u8 + Send>"
+        );
+        assert_eq!(text(syn::parse_quote!(*const T)), "*const T");
+    }
+
+    #[test]
+    fn parses_path_with_generic_arguments() {
+        let ty = TypeRef::from(&syn::parse_quote!(
+            std::collections::HashMap<'a, String, [u8; N]>
+        ));
+
+        let segments = match ty.kind() {
+            Kind::Path(segments) => segments,
+            _ => unreachable!(),
+        };
+
+        assert_eq!(
+            segments.iter().map(|s| s.identifier()).collect::<Vec<_>>(),
+            vec!["std", "collections", "HashMap"]
+        );
+
+        let arguments = segments[2].arguments();
+        assert!(matches!(&arguments[0], GenericArgument::Lifetime(l) if l == "'a"));
+        assert!(matches!(&arguments[1], GenericArgument::Type(t) if t.text() == "String"));
+
+        match &arguments[2] {
+            GenericArgument::Type(ty) => match ty.kind() {
+                Kind::Array { elem, length } => {
+                    assert_eq!(elem.text(), "u8");
+                    assert_eq!(length, "N");
+                }
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn parses_tuple() {
+        let ty = TypeRef::from(&syn::parse_quote!((u8, &[u16])));
+
+        match ty.kind() {
+            Kind::Tuple(elems) => {
+                assert_eq!(elems.len(), 2);
+                assert_eq!(elems[1].text(), "&[u16]");
+                assert!(matches!(elems[1].kind(), Kind::Reference { .. }));
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn str_tokens_round_trip_any_string() {
+        for value in [
+            "",
+            "\"#",
+            "\"##\"###",
+            "r#\"raw\"#",
+            "back\\slash",
+            "\\n is not a newline",
+            "tab\tand\nnewline",
+            "braces { } and brackets [ ]",
+            "unicode: ünïcødé 🦀 \u{200b}",
+        ] {
+            let call = syn::parse2::<syn::ExprCall>(str_tokens(value)).unwrap();
+
+            match &call.args[0] {
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(lit),
+                    ..
+                }) => assert_eq!(lit.value(), value),
+                _ => unreachable!(),
+            }
+        }
+    }
+}
@@ -0,0 +1,745 @@
+//! Rust unions.
+
+#[cfg(feature = "alloc")]
+mod builder;
+
+#[cfg(feature = "alloc")]
+pub use builder::Builder;
+
+#[cfg(feature = "parse")]
+use proc_macro2::TokenStream;
+#[cfg(feature = "parse")]
+use quote::quote;
+#[cfg(feature = "parse")]
+use quote::ToTokens;
+
+use crate::borrow::Slice;
+use crate::borrow::Str;
+#[cfg(feature = "parse")]
+use crate::parse::Resolved;
+#[cfg(feature = "parse")]
+use crate::r#struct::field;
+use crate::r#struct::Field;
+#[cfg(feature = "parse")]
+use crate::r#type::option_tokens;
+#[cfg(feature = "parse")]
+use crate::r#type::slice_tokens;
+#[cfg(feature = "parse")]
+use crate::r#type::str_tokens;
+use crate::Attribute;
+use crate::Documentation;
+use crate::Generics;
+use crate::Location;
+use crate::Metadata;
+#[cfg(feature = "parse")]
+use crate::Options;
+use crate::Visibility;
+
+/// An error related to a [`Union`].
+#[cfg(feature = "parse")]
+pub enum Error {
+    /// Encountered an unsupported expression for a documentation attribute.
+    UnsupportedExpression(Box<syn::Expr>),
+
+    /// Encountered an unsupported expression literal for a documentation attribute.
+    UnsupportedExpressionLiteral(Box<syn::ExprLit>),
+
+    /// Encountered an invalid option within an `#[introspect(..)]` helper attribute.
+    InvalidOption(syn::Error),
+
+    /// Encountered an error with one of the fields of the union.
+    Field(field::Error),
+
+    /// Encountered multiple errors (e.g., within several attributes), all of which
+    /// are reported at once.
+    Multiple(Vec<Error>),
+}
+
+#[cfg(feature = "parse")]
+impl core::fmt::Debug for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnsupportedExpression(_) => f.debug_tuple("UnsupportedExpression").finish(),
+            Self::UnsupportedExpressionLiteral(_) => {
+                f.debug_tuple("UnsupportedExpressionLiteral").finish()
+            }
+            Self::InvalidOption(err) => f.debug_tuple("InvalidOption").field(err).finish(),
+            Self::Field(err) => f.debug_tuple("Field").field(err).finish(),
+            Self::Multiple(errors) => f.debug_tuple("Multiple").field(errors).finish(),
+        }
+    }
+}
+
+#[cfg(feature = "parse")]
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::UnsupportedExpression(_) => {
+                write!(f, "unsupported doc attribute expression")
+            }
+            Error::UnsupportedExpressionLiteral(_) => {
+                write!(f, "unsupported doc attribute literal")
+            }
+            Error::InvalidOption(err) => write!(f, "invalid `introspect` option: {err}"),
+            Error::Field(err) => write!(f, "field error: {err}"),
+            Error::Multiple(errors) => {
+                for (i, err) in errors.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, "; ")?;
+                    }
+
+                    write!(f, "{err}")?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "parse")]
+impl core::error::Error for Error {}
+
+#[cfg(feature = "parse")]
+impl From<Error> for syn::Error {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::UnsupportedExpression(expr) => crate::parse::unsupported_expression(&expr),
+            Error::UnsupportedExpressionLiteral(expr_lit) => {
+                crate::parse::unsupported_expression_literal(&expr_lit)
+            }
+            Error::InvalidOption(err) => err,
+            Error::Field(err) => err.into(),
+            Error::Multiple(errors) => crate::parse::into_syn_error(errors),
+        }
+    }
+}
+
+/// A [`Result`](std::result::Result) with an [`Error`].
+#[cfg(feature = "parse")]
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The parts of a [`Union`], which are passed to [`Union::new()`].
+///
+/// Unlike the builder, the parts can be constructed within a `const` context (as
+/// they are by the `Introspect` derive macro).
+#[derive(Clone, Debug)]
+pub struct Parts {
+    /// The identifier for the union.
+    pub identifier: Str,
+
+    /// The fields of the union.
+    pub fields: Slice<Field>,
+
+    /// The generics of the union.
+    pub generics: Generics,
+
+    /// The visibility of the union.
+    pub visibility: Visibility,
+
+    /// The attributes of the union.
+    pub attributes: Slice<Attribute>,
+
+    /// The public name for the union, if it was renamed.
+    pub rename: Option<Str>,
+
+    /// Whether or not the union is hidden.
+    pub hidden: bool,
+
+    /// The user-defined metadata for the union.
+    pub metadata: Metadata,
+
+    /// The location at which the union is defined, if it is known.
+    pub location: Option<Location>,
+
+    /// The documentation for the union, if it exists.
+    pub documentation: Option<Str>,
+
+    /// The raw documentation for the union, if it exists.
+    pub documentation_raw: Option<Str>,
+}
+
+/// A union.
+#[derive(Clone, Debug)]
+pub struct Union {
+    /// The parts of the union.
+    parts: Parts,
+}
+
+impl Union {
+    /// Creates a new [`Union`] from its [`Parts`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    /// use core::union::Parts;
+    ///
+    /// let union_ = core::Union::new(Parts {
+    ///     identifier: "Name".into(),
+    ///     fields: Vec::new().into(),
+    ///     generics: core::Generics::default(),
+    ///     visibility: core::Visibility::Public,
+    ///     attributes: Vec::new().into(),
+    ///     rename: None,
+    ///     hidden: false,
+    ///     metadata: core::Metadata::default(),
+    ///     location: None,
+    ///     documentation: Some("Documentation.".into()),
+    ///     documentation_raw: Some(" Documentation.".into()),
+    /// });
+    ///
+    /// assert_eq!(union_.identifier(), "Name");
+    /// ```
+    pub const fn new(parts: Parts) -> Self {
+        Self { parts }
+    }
+
+    /// Consumes `self` to produce a [`Union`] defined at the provided location.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let location = core::Location::new(
+    ///     "src/config.rs".into(),
+    ///     "my_crate::config".into(),
+    ///     42,
+    ///     1
+    /// );
+    ///
+    /// let union_ = core::union::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .try_build()?
+    ///                 .with_location(location);
+    ///
+    /// assert_eq!(union_.location().map(|location| location.file()), Some("src/config.rs"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_location(mut self, location: Location) -> Self {
+        self.parts.location = Some(location);
+        self
+    }
+
+    /// Consumes `self` to produce a [`Union`] with the provided fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    /// use core::borrow::Slice;
+    ///
+    /// let field = core::r#struct::field::Builder::default()
+    ///                 .identifier("integer")
+    ///                 .ty(core::TypeRef::from(&syn::parse_quote!(u32)))
+    ///                 .try_build()?;
+    ///
+    /// let union_ = core::union::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .try_build()?
+    ///                 .with_fields(Slice::from(vec![field]));
+    ///
+    /// assert_eq!(union_.fields().len(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_fields(mut self, fields: Slice<Field>) -> Self {
+        self.parts.fields = fields;
+        self
+    }
+
+    /// Consumes `self` to produce a [`Union`] with the provided documentation (or
+    /// without any documentation if `None` is provided).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let union_ = core::union::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .try_build()?
+    ///                 .with_documentation(Some("Documentation.".into()));
+    ///
+    /// assert_eq!(union_.documentation(), Some("Documentation."));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_documentation(mut self, documentation: Option<Str>) -> Self {
+        self.parts.documentation = documentation;
+        self
+    }
+
+    /// Consumes `self` to produce a [`Union`] with the provided raw
+    /// documentation (or without any raw documentation if `None` is provided).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let union_ = core::union::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .try_build()?
+    ///                 .with_documentation_raw(Some(" Documentation.".into()));
+    ///
+    /// assert_eq!(union_.documentation_raw(), Some(" Documentation."));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_documentation_raw(mut self, documentation_raw: Option<Str>) -> Self {
+        self.parts.documentation_raw = documentation_raw;
+        self
+    }
+
+    /// Gets the identifier of the [`Union`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let union_ = core::union::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .documentation("Documentation.")
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(union_.identifier(), "Name");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn identifier(&self) -> &str {
+        self.parts.identifier.as_str()
+    }
+
+    /// Gets the fields of the [`Union`] by reference.
+    ///
+    /// Fields that are marked with `#[introspect(skip)]` are not included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let field = core::r#struct::field::Builder::default()
+    ///                 .identifier("integer")
+    ///                 .ty(core::TypeRef::from(&syn::parse_quote!(u32)))
+    ///                 .try_build()?;
+    ///
+    /// let union_ = core::union::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .field(field)
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(union_.fields().len(), 1);
+    /// assert_eq!(union_.fields()[0].identifier(), Some("integer"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn fields(&self) -> &[Field] {
+        self.parts.fields.as_slice()
+    }
+
+    /// Gets the generics of the [`Union`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let union_ = core::union::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .generics(core::Generics::from(&syn::parse_quote!(<T>)))
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(union_.generics().params().len(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn generics(&self) -> &Generics {
+        &self.parts.generics
+    }
+
+    /// Gets the public name of the [`Union`] by reference.
+    ///
+    /// This is the name provided via `#[introspect(rename = "...")]` or, if the union
+    /// was not renamed, the identifier of the union.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let union_ = core::union::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .rename("Renamed")
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(union_.identifier(), "Name");
+    /// assert_eq!(union_.name(), "Renamed");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn name(&self) -> &str {
+        self.parts
+            .rename
+            .as_deref()
+            .unwrap_or(self.parts.identifier.as_str())
+    }
+
+    /// Gets whether the [`Union`] is hidden (via `#[introspect(hidden)]`).
+    ///
+    /// Hidden unions are still introspected, but consumers are expected to leave them
+    /// out of user-facing output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let union_ = core::union::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .hidden(true)
+    ///                 .try_build()?;
+    ///
+    /// assert!(union_.is_hidden());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn is_hidden(&self) -> bool {
+        self.parts.hidden
+    }
+
+    /// Gets the user-defined metadata of the [`Union`] by reference.
+    ///
+    /// This is the metadata provided via `#[introspect(meta(..))]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let union_ = core::union::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .metadata("unit", "ms")
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(union_.metadata().get("unit").and_then(|value| value.as_str()), Some("ms"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn metadata(&self) -> &Metadata {
+        &self.parts.metadata
+    }
+
+    /// Gets the location at which the [`Union`] is defined by reference, if it is
+    /// known.
+    ///
+    /// The `Introspect` derive macro always provides the location.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let location = core::Location::new(
+    ///     "src/config.rs".into(),
+    ///     "my_crate::config".into(),
+    ///     42,
+    ///     1
+    /// );
+    ///
+    /// let union_ = core::union::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .location(location)
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(union_.location().map(|location| location.line()), Some(42));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn location(&self) -> Option<&Location> {
+        self.parts.location.as_ref()
+    }
+
+    /// Gets the visibility of the [`Union`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let union_ = core::union::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .visibility(core::Visibility::Crate)
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(union_.visibility(), &core::Visibility::Crate);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn visibility(&self) -> &Visibility {
+        &self.parts.visibility
+    }
+
+    /// Gets the attributes of the [`Union`] by reference.
+    ///
+    /// Attributes are only captured by the `Introspect` derive macro when the
+    /// `attributes` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let attribute: syn::Attribute = syn::parse_quote!(#[deprecated]);
+    ///
+    /// let union_ = core::union::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .attribute(core::Attribute::from(&attribute))
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(union_.attributes()[0].path(), "deprecated");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn attributes(&self) -> &[Attribute] {
+        self.parts.attributes.as_slice()
+    }
+
+    /// Gets the documentation of the [`Union`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let union_ = core::union::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .documentation("Documentation.")
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(union_.documentation(), Some("Documentation."));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn documentation(&self) -> Option<&str> {
+        self.parts.documentation.as_deref()
+    }
+
+    /// Gets the raw documentation of the [`Union`] by reference.
+    ///
+    /// Unlike [`Union::documentation()`], the text of each documentation attribute
+    /// is kept exactly as written: neither the common leading whitespace nor the
+    /// decoration of block comments (`/** .. */`) is stripped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let union_ = core::union::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .documentation_raw(" Documentation.")
+    ///                 .try_build()?;
+    ///
+    /// assert_eq!(union_.documentation_raw(), Some(" Documentation."));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn documentation_raw(&self) -> Option<&str> {
+        self.parts.documentation_raw.as_deref()
+    }
+
+    /// Gets the documentation of the [`Union`] parsed into a summary, a body, and
+    /// sections (see [`Documentation`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let union_ = core::union::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .documentation("The summary.\n\nThe body.\n\n# Panics\n\nNever.")
+    ///                 .try_build()?;
+    ///
+    /// let documentation = union_.documentation_parsed().unwrap();
+    /// assert_eq!(documentation.summary(), "The summary.");
+    /// assert_eq!(documentation.body(), "The body.");
+    /// assert_eq!(documentation.section("Panics").unwrap().content(), "Never.");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn documentation_parsed(&self) -> Option<Documentation<'_>> {
+        self.documentation().map(Documentation::new)
+    }
+}
+
+#[cfg(feature = "parse")]
+impl Union {
+    /// Converts the [`Union`] into the tokens that construct it, replacing the
+    /// parts that are provided by `resolved` (see [`Resolved`]).
+    pub fn to_resolved_tokens(&self, resolved: &Resolved) -> TokenStream {
+        let identifier = str_tokens(&self.parts.identifier);
+        let fields = resolved.fields_tokens(&self.parts.fields);
+        let generics = &self.parts.generics;
+        let visibility = &self.parts.visibility;
+        let attributes = slice_tokens(self.parts.attributes.iter());
+        let rename = option_tokens(self.parts.rename.as_deref().map(str_tokens));
+        let hidden = self.parts.hidden;
+        let metadata = &self.parts.metadata;
+        let location = resolved.location_tokens(self.parts.location.as_ref());
+        let documentation = resolved.documentation_tokens(self.parts.documentation.as_deref());
+        let documentation_raw =
+            resolved.documentation_raw_tokens(self.parts.documentation_raw.as_deref());
+
+        quote! {
+            ::introspect::Union::new(::introspect::union::Parts {
+                identifier: #identifier,
+                fields: #fields,
+                generics: #generics,
+                visibility: #visibility,
+                attributes: #attributes,
+                rename: #rename,
+                hidden: #hidden,
+                metadata: #metadata,
+                location: #location,
+                documentation: #documentation,
+                documentation_raw: #documentation_raw,
+            })
+        }
+    }
+}
+
+#[cfg(feature = "parse")]
+impl ToTokens for Union {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(self.to_resolved_tokens(&Resolved::default()))
+    }
+}
+
+#[cfg(feature = "parse")]
+impl TryFrom<&syn::ItemUnion> for Union {
+    type Error = Error;
+
+    fn try_from(value: &syn::ItemUnion) -> Result<Self> {
+        let mut errors = Vec::new();
+
+        let options = match Options::try_from(value.attrs.as_slice()) {
+            Ok(options) => options,
+            Err(err) => {
+                errors.push(Error::InvalidOption(err));
+                Options::default()
+            }
+        };
+
+        if let Err(err) = options.deny_skip("unions") {
+            errors.push(Error::InvalidOption(err));
+        }
+
+        if let Err(err) = options.deny_reflect("unions") {
+            errors.push(Error::InvalidOption(err));
+        }
+
+        if let Err(err) = options.deny_value("unions") {
+            errors.push(Error::InvalidOption(err));
+        }
+
+        let (documentation, documentation_raw) = crate::parse::documentation(
+            &value.attrs,
+            &mut errors,
+            Error::UnsupportedExpression,
+            Error::UnsupportedExpressionLiteral,
+        );
+
+        let fields = value
+            .fields
+            .named
+            .iter()
+            .enumerate()
+            // NOTE: invalid options are reported when the field itself is converted.
+            .filter(|(_, field)| {
+                !Options::try_from(field.attrs.as_slice()).is_ok_and(|options| options.skip())
+            })
+            .filter_map(|(index, field)| match Field::try_from((index, field)) {
+                Ok(field) => Some(field),
+                Err(err) => {
+                    errors.push(Error::Field(err));
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(err) = crate::parse::combine(errors, Error::Multiple) {
+            return Err(err);
+        }
+
+        Ok(Self::new(Parts {
+            identifier: value.ident.to_string().into(),
+            fields: fields.into(),
+            generics: Generics::from(&value.generics),
+            visibility: Visibility::from(&value.vis),
+            attributes: crate::attribute::capture(&value.attrs).into(),
+            rename: options.rename().map(|rename| rename.to_string().into()),
+            hidden: options.hidden(),
+            metadata: options.metadata().clone(),
+            location: None,
+            documentation: documentation.map(Into::into),
+            documentation_raw: documentation_raw.map(Into::into),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn try_from_captures_the_union() {
+        let item: syn::ItemUnion = syn::parse_quote! {
+            /// A value that is shared with C code.
+            #[introspect(rename = "Shared")]
+            pub union Value<T: Copy> {
+                integer: u32,
+                other: T,
+            }
+        };
+
+        let union_ = Union::try_from(&item).unwrap();
+
+        assert_eq!(union_.identifier(), "Value");
+        assert_eq!(union_.name(), "Shared");
+        assert_eq!(union_.visibility(), &Visibility::Public);
+        assert_eq!(union_.generics().params().len(), 1);
+        assert_eq!(
+            union_
+                .fields()
+                .iter()
+                .map(|field| (field.identifier().unwrap(), field.ty().text()))
+                .collect::<Vec<_>>(),
+            [("integer", "u32"), ("other", "T")]
+        );
+        assert_eq!(
+            union_.documentation(),
+            Some("A value that is shared with C code.")
+        );
+    }
+
+    #[test]
+    fn try_from_rejects_skip() {
+        let item: syn::ItemUnion = syn::parse_quote! {
+            #[introspect(skip)]
+            union Value {
+                integer: u32,
+            }
+        };
+
+        assert!(matches!(
+            Union::try_from(&item),
+            Err(Error::InvalidOption(_))
+        ));
+    }
+}
@@ -0,0 +1,287 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::metadata::Value;
+use crate::r#struct::Field;
+use crate::union::Parts;
+use crate::Attribute;
+use crate::Generics;
+use crate::Location;
+use crate::Metadata;
+use crate::Union;
+use crate::Visibility;
+
+/// An error related to a [`Builder`].
+#[derive(Debug)]
+pub enum Error {
+    /// An identifier was never added to the [`Builder`].
+    MissingIdentifier,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::MissingIdentifier => write!(f, "missing identifier"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+/// A [`Result`](core::result::Result) with an [`Error`].
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// A builder for a [`Union`].
+#[derive(Debug, Default)]
+pub struct Builder {
+    /// An identifier for the union.
+    identifier: Option<String>,
+
+    /// The fields of the union.
+    fields: Vec<Field>,
+
+    /// The generics for the union.
+    generics: Generics,
+
+    /// The visibility of the union.
+    visibility: Visibility,
+
+    /// The attributes of the union.
+    attributes: Vec<Attribute>,
+
+    /// The public name for the union, if it was renamed.
+    rename: Option<String>,
+
+    /// Whether or not the union is hidden.
+    hidden: bool,
+
+    /// The user-defined metadata for the union.
+    metadata: Metadata,
+
+    /// The location at which the union is defined, if it is known.
+    location: Option<Location>,
+
+    /// The documentation for the union, if it exists.
+    documentation: Option<String>,
+
+    /// The raw documentation for the union, if it exists.
+    documentation_raw: Option<String>,
+}
+
+impl Builder {
+    /// Sets the identifier for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::union::Builder::default()
+    ///                 .identifier("Name");
+    /// ```
+    pub fn identifier<S: Into<String>>(mut self, value: S) -> Self {
+        self.identifier = Some(value.into());
+        self
+    }
+
+    /// Adds a field to this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let field = core::r#struct::field::Builder::default()
+    ///                 .identifier("integer")
+    ///                 .ty(core::TypeRef::from(&syn::parse_quote!(u32)))
+    ///                 .try_build()?;
+    ///
+    /// let builder = core::union::Builder::default()
+    ///                 .field(field);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn field(mut self, value: Field) -> Self {
+        self.fields.push(value);
+        self
+    }
+
+    /// Sets the generics for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::union::Builder::default()
+    ///                 .generics(core::Generics::from(&syn::parse_quote!(<T>)));
+    /// ```
+    pub fn generics(mut self, value: Generics) -> Self {
+        self.generics = value;
+        self
+    }
+
+    /// Sets the visibility for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::union::Builder::default()
+    ///                 .visibility(core::Visibility::Public);
+    /// ```
+    pub fn visibility(mut self, value: Visibility) -> Self {
+        self.visibility = value;
+        self
+    }
+
+    /// Adds an attribute to this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let attribute: syn::Attribute = syn::parse_quote!(#[deprecated]);
+    ///
+    /// let builder = core::union::Builder::default()
+    ///                 .attribute(core::Attribute::from(&attribute));
+    /// ```
+    pub fn attribute(mut self, value: Attribute) -> Self {
+        self.attributes.push(value);
+        self
+    }
+
+    /// Sets the public name for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::union::Builder::default()
+    ///                 .rename("Renamed");
+    /// ```
+    pub fn rename<S: Into<String>>(mut self, value: S) -> Self {
+        self.rename = Some(value.into());
+        self
+    }
+
+    /// Sets whether the union is hidden for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::union::Builder::default()
+    ///                 .hidden(true);
+    /// ```
+    pub fn hidden(mut self, value: bool) -> Self {
+        self.hidden = value;
+        self
+    }
+
+    /// Adds a user-defined metadata entry to this [`Builder`].
+    ///
+    /// If the key already exists, its value is replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::union::Builder::default()
+    ///                 .metadata("unit", "ms");
+    /// ```
+    pub fn metadata<K: Into<String>, V: Into<Value>>(mut self, key: K, value: V) -> Self {
+        self.metadata.insert(key, value);
+        self
+    }
+
+    /// Sets the location for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::union::Builder::default()
+    ///                 .location(core::Location::new(
+    ///                     "src/config.rs".into(),
+    ///                     "my_crate::config".into(),
+    ///                     42,
+    ///                     1
+    ///                 ));
+    /// ```
+    pub fn location(mut self, value: Location) -> Self {
+        self.location = Some(value);
+        self
+    }
+
+    /// Sets the documentation for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::union::Builder::default()
+    ///                 .documentation("Documentation.");
+    /// ```
+    pub fn documentation<S: Into<String>>(mut self, value: S) -> Self {
+        self.documentation = Some(value.into());
+        self
+    }
+
+    /// Sets the raw documentation for this [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let builder = core::union::Builder::default()
+    ///                 .documentation_raw(" Documentation.");
+    /// ```
+    pub fn documentation_raw<S: Into<String>>(mut self, value: S) -> Self {
+        self.documentation_raw = Some(value.into());
+        self
+    }
+
+    /// Consume `self` to produce an immutable [`Union`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// let union_ = core::union::Builder::default()
+    ///                 .identifier("Name")
+    ///                 .documentation("Documentation.")
+    ///                 .try_build()?;
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_build(self) -> Result<Union> {
+        let identifier = match self.identifier {
+            Some(identifier) => identifier,
+            None => return Err(Error::MissingIdentifier),
+        };
+
+        Ok(Union::new(Parts {
+            identifier: identifier.into(),
+            fields: self.fields.into(),
+            generics: self.generics,
+            visibility: self.visibility,
+            attributes: self.attributes.into(),
+            rename: self.rename.map(Into::into),
+            hidden: self.hidden,
+            metadata: self.metadata,
+            location: self.location,
+            documentation: self.documentation.map(Into::into),
+            documentation_raw: self.documentation_raw.map(Into::into),
+        }))
+    }
+}
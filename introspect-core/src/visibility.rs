@@ -0,0 +1,147 @@
+//! Rust visibility.
+
+#[cfg(feature = "parse")]
+use proc_macro2::TokenStream;
+#[cfg(feature = "parse")]
+use quote::quote;
+#[cfg(feature = "parse")]
+use quote::ToTokens;
+
+use crate::borrow::Str;
+#[cfg(feature = "parse")]
+use crate::r#type::normalize;
+#[cfg(feature = "parse")]
+use crate::r#type::str_tokens;
+
+/// The visibility of a Rust entity or field.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum Visibility {
+    /// Visible only within the current module (i.e., no visibility modifier or
+    /// `pub(self)`).
+    #[default]
+    Private,
+
+    /// Visible everywhere (i.e., `pub`).
+    Public,
+
+    /// Visible within the current crate (i.e., `pub(crate)`).
+    Crate,
+
+    /// Visible within the parent module (i.e., `pub(super)`).
+    Super,
+
+    /// Visible within a specific module (i.e., `pub(in path)`).
+    Restricted {
+        /// The normalized source text of the path.
+        path: Str,
+    },
+}
+
+impl Visibility {
+    /// Returns whether the visibility is [`Visibility::Public`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect_core as core;
+    ///
+    /// assert!(core::Visibility::Public.is_public());
+    /// assert!(!core::Visibility::Crate.is_public());
+    /// ```
+    pub fn is_public(&self) -> bool {
+        matches!(self, Visibility::Public)
+    }
+}
+
+#[cfg(feature = "parse")]
+impl ToTokens for Visibility {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Visibility::Private => quote!(::introspect::visibility::Visibility::Private),
+            Visibility::Public => quote!(::introspect::visibility::Visibility::Public),
+            Visibility::Crate => quote!(::introspect::visibility::Visibility::Crate),
+            Visibility::Super => quote!(::introspect::visibility::Visibility::Super),
+            Visibility::Restricted { path } => {
+                let path = str_tokens(path);
+                quote!(::introspect::visibility::Visibility::Restricted { path: #path })
+            }
+        })
+    }
+}
+
+#[cfg(feature = "parse")]
+impl From<&syn::Visibility> for Visibility {
+    fn from(value: &syn::Visibility) -> Self {
+        match value {
+            syn::Visibility::Public(_) => Visibility::Public,
+            syn::Visibility::Inherited => Visibility::Private,
+            syn::Visibility::Restricted(restricted) => {
+                if restricted.path.is_ident("crate") {
+                    Visibility::Crate
+                } else if restricted.path.is_ident("super") {
+                    Visibility::Super
+                } else if restricted.path.is_ident("self") {
+                    Visibility::Private
+                } else {
+                    Visibility::Restricted {
+                        path: normalize(&restricted.path).into(),
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_captures_each_visibility() {
+        let visibility = |vis: syn::Visibility| Visibility::from(&vis);
+
+        assert_eq!(visibility(syn::parse_quote!()), Visibility::Private);
+        assert_eq!(
+            visibility(syn::parse_quote!(pub(self))),
+            Visibility::Private
+        );
+        assert_eq!(visibility(syn::parse_quote!(pub)), Visibility::Public);
+        assert_eq!(visibility(syn::parse_quote!(pub(crate))), Visibility::Crate);
+        assert_eq!(
+            visibility(syn::parse_quote!(pub(in crate))),
+            Visibility::Crate
+        );
+        assert_eq!(visibility(syn::parse_quote!(pub(super))), Visibility::Super);
+        assert_eq!(
+            visibility(syn::parse_quote!(pub(in crate::foo::bar))),
+            Visibility::Restricted {
+                path: "crate::foo::bar".into()
+            }
+        );
+    }
+
+    #[test]
+    fn converts_to_tokens_correctly() {
+        assert_eq!(
+            Visibility::Crate.to_token_stream().to_string(),
+            quote! {
+                ::introspect::visibility::Visibility::Crate
+            }
+            .to_string()
+        );
+
+        assert_eq!(
+            Visibility::Restricted {
+                path: "crate::foo".into()
+            }
+            .to_token_stream()
+            .to_string(),
+            quote! {
+                ::introspect::visibility::Visibility::Restricted {
+                    path: ::introspect::borrow::Str::Borrowed("crate::foo")
+                }
+            }
+            .to_string()
+        );
+    }
+}
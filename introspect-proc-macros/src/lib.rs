@@ -6,126 +6,582 @@
 #![warn(missing_debug_implementations)]
 #![warn(rustdoc::broken_intra_doc_links)]
 
-use core::panic;
-
-use introspect_core::r#enum::Variant;
+use introspect_core::parse::Resolved;
 use introspect_core::r#struct::Field;
-use introspect_core::Entity;
 use introspect_core::Enum;
+use introspect_core::Options;
 use introspect_core::Struct;
+use introspect_core::Union;
+use proc_macro2::Span;
 use proc_macro2::TokenStream;
+use quote::format_ident;
 use quote::quote;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::spanned::Spanned;
 use syn::Item;
 
 /// The primary `derive` procedural macro that implements the introspection traits.
-#[proc_macro_derive(Introspect)]
+#[proc_macro_derive(Introspect, attributes(introspect))]
 pub fn introspect(stream: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let item = syn::parse_macro_input!(stream as Item);
 
     match item {
         Item::Enum(enum_) => parse_item_enum(enum_),
         Item::Struct(struct_) => parse_item_struct(struct_),
-        _ => {
-            quote! {
-                compile_error!("Introspect can only be derived for `enum`s and `struct`s")
-            }
-        }
+        Item::Union(union_) => parse_item_union(union_),
+        item => Err(syn::Error::new_spanned(
+            item,
+            "Introspect can only be derived for `enum`s, `struct`s, and `union`s",
+        )),
     }
+    .unwrap_or_else(syn::Error::into_compile_error)
     .into()
 }
 
-fn parse_item_enum(item: syn::ItemEnum) -> TokenStream {
+fn parse_item_enum(item: syn::ItemEnum) -> syn::Result<TokenStream> {
     let ident = &item.ident;
 
-    let enum_ = match Enum::try_from(&item) {
-        Ok(enum_) => Entity::Enum(enum_),
-        // SAFETY: this panic is okay because it happens during the compilation
-        // process. As such, the Rust compiler will complain with this error instead
-        // of happening at program runtime.
-        Err(err) => panic!("error: {err}"),
-    };
+    // NOTE: converting the enum also converts each of its variants, so the errors
+    // of the enum and all of its variants are reported at once.
+    let enum_ = Enum::try_from(&item)?;
 
+    // NOTE: the discriminants of fieldless enums can always be resolved by casting
+    // each variant to an integer, which also covers implicit discriminants and
+    // discriminants that are written as constant expressions. The discriminants of
+    // `#[repr(u128)]` enums are not resolved, as they may exceed `i128::MAX`.
+    let castable = enum_.repr() != Some("u128")
+        && item
+            .variants
+            .iter()
+            .all(|variant| matches!(variant.fields, syn::Fields::Unit));
+
+    // NOTE: the enum holds its variants that are not skipped in order, so they are
+    // paired with the syntax of the same variants.
     let variants = item
         .variants
         .iter()
-        .map(|field| match Variant::try_from(field) {
-            Ok(variant) => variant,
-            // SAFETY: this panic is okay because it happens during the compilation
-            // process. As such, the Rust compiler will complain with this error instead
-            // of happening at program runtime.
-            Err(err) => panic!("error: {err}"),
+        .filter(|variant| !skipped(&variant.attrs))
+        .zip(enum_.variants())
+        .map(|(variant, introspected)| {
+            let ident = &variant.ident;
+            let resolved = Resolved {
+                fields: Some(resolve_fields(variant.fields.iter())?),
+                discriminant_value: castable.then(|| quote!(Self::#ident as i128)),
+                ..resolve(ident.span(), &variant.attrs)
+            };
+
+            let cfgs = cfgs(&variant.attrs);
+            let variant = introspected.to_resolved_tokens(&resolved);
+
+            Ok(quote!(#(#cfgs)* #variant))
         })
-        .map(introspect_core::Member::Variant)
-        .collect::<Vec<_>>();
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let resolved = Resolved {
+        variants: Some(variants),
+        ..resolve(ident.span(), &item.attrs)
+    };
+    let entity = enum_.to_resolved_tokens(&resolved);
+    let indices = resolve_variant_indices(&item);
+    let mut tokens = implement(
+        ident,
+        &item.generics,
+        quote!(::introspect::Entity::Enum(#entity)),
+    );
+    tokens.extend(implement_kind(
+        ident,
+        &item.generics,
+        "Enum",
+        quote! {
+            fn variant_index(&self) -> ::core::option::Option<usize> {
+                match *self {
+                    #(#indices)*
+                }
+            }
+        },
+    ));
+
+    if Options::try_from(item.attrs.as_slice())?.value() {
+        tokens.extend(implement_value(
+            ident,
+            &item.generics,
+            resolve_enum_value(&item),
+        ));
+    }
+
+    Ok(tokens)
+}
+
+fn parse_item_struct(item: syn::ItemStruct) -> syn::Result<TokenStream> {
+    let ident = &item.ident;
+
+    // NOTE: converting the struct also converts each of its fields, so the errors
+    // of the struct and all of its fields are reported at once.
+    let struct_ = Struct::try_from(&item)?;
+    let resolved = Resolved {
+        fields: Some(resolve_fields(item.fields.iter())?),
+        ..resolve(ident.span(), &item.attrs)
+    };
+    let entity = struct_.to_resolved_tokens(&resolved);
+    let mut tokens = implement(
+        ident,
+        &item.generics,
+        quote!(::introspect::Entity::Struct(#entity)),
+    );
+    tokens.extend(implement_kind(
+        ident,
+        &item.generics,
+        "Struct",
+        TokenStream::new(),
+    ));
+
+    let options = Options::try_from(item.attrs.as_slice())?;
+
+    if options.reflect() {
+        tokens.extend(implement_reflect(&item));
+    }
+
+    if options.value() {
+        tokens.extend(implement_value(
+            ident,
+            &item.generics,
+            resolve_struct_value(&item),
+        ));
+    }
+
+    Ok(tokens)
+}
+
+fn parse_item_union(item: syn::ItemUnion) -> syn::Result<TokenStream> {
+    let ident = &item.ident;
+
+    // NOTE: converting the union also converts each of its fields, so the errors
+    // of the union and all of its fields are reported at once.
+    let union_ = Union::try_from(&item)?;
+    let resolved = Resolved {
+        fields: Some(resolve_fields(item.fields.named.iter())?),
+        ..resolve(ident.span(), &item.attrs)
+    };
+    let entity = union_.to_resolved_tokens(&resolved);
+    let mut tokens = implement(
+        ident,
+        &item.generics,
+        quote!(::introspect::Entity::Union(#entity)),
+    );
+    tokens.extend(implement_kind(
+        ident,
+        &item.generics,
+        "Union",
+        TokenStream::new(),
+    ));
+
+    Ok(tokens)
+}
+
+/// Implements the introspection traits for an entity.
+///
+/// The entity is embedded as a `'static` [`EntityDescriptor`] constant, which the
+/// remaining traits borrow from (including the members, which are borrowed from the
+/// entity itself).
+///
+/// [`EntityDescriptor`]: introspect_core::EntityDescriptor
+fn implement(ident: &syn::Ident, generics: &syn::Generics, entity: TokenStream) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     quote! {
         #[automatically_derived]
-        impl ::introspect::IntrospectedEntity for #ident {
+        impl #impl_generics ::introspect::IntrospectedEntity for #ident #ty_generics #where_clause {
 
-            fn introspected_entity() -> ::introspect::Entity {
-                #enum_
+            fn introspected_entity() -> &'static ::introspect::Entity {
+                <Self as ::introspect::Introspected>::INTROSPECTION.entity()
             }
         }
 
         #[automatically_derived]
-        impl ::introspect::IntrospectedMembers for #ident {
+        impl #impl_generics ::introspect::IntrospectedMembers for #ident #ty_generics #where_clause {
 
-            fn introspected_members() -> Vec<::introspect::Member> {
-                vec![
-                    #(#variants),*
-                ]
+            fn introspected_members() -> ::introspect::Members<'static> {
+                <Self as ::introspect::Introspected>::INTROSPECTION.members()
             }
         }
 
         #[automatically_derived]
-        impl ::introspect::Introspected for #ident {}
+        impl #impl_generics ::introspect::Introspected for #ident #ty_generics #where_clause {
+            const INTROSPECTION: &'static ::introspect::EntityDescriptor =
+                &::introspect::EntityDescriptor::new(#entity);
+        }
+    }
+}
+
+/// Implements the kind-specific introspection trait (such as `IntrospectedStruct`)
+/// for an entity of the provided kind (such as `"Struct"`), along with any other
+/// items of the trait that are specific to the entity.
+///
+/// NOTE: the entity is borrowed from the `'static` [`EntityDescriptor`] constant
+/// (see [`implement()`]), which always holds an entity of the same kind.
+///
+/// [`EntityDescriptor`]: introspect_core::EntityDescriptor
+fn implement_kind(
+    ident: &syn::Ident,
+    generics: &syn::Generics,
+    kind: &str,
+    items: TokenStream,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let kind = syn::Ident::new(kind, Span::call_site());
+    let trait_ = format_ident!("Introspected{}", kind);
+    let function = format_ident!("introspected_{}", kind.to_string().to_lowercase());
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics ::introspect::#trait_ for #ident #ty_generics #where_clause {
+            fn #function() -> &'static ::introspect::#kind {
+                match <Self as ::introspect::Introspected>::INTROSPECTION.entity() {
+                    ::introspect::Entity::#kind(entity) => entity,
+                    _ => ::core::unreachable!(),
+                }
+            }
+
+            #items
+        }
     }
 }
 
-fn parse_item_struct(item: syn::ItemStruct) -> TokenStream {
+/// Implements `Reflect` for a struct that opted in via `#[introspect(reflect)]`.
+///
+/// NOTE: each field that is not skipped is borrowed as a `dyn Any`, so every type
+/// and lifetime parameter of the struct must outlive `'static`. Further, setting a
+/// field requires an allocator, so `set()` is only implemented when the `alloc`
+/// feature of `introspect` is enabled.
+fn implement_reflect(item: &syn::ItemStruct) -> TokenStream {
     let ident = &item.ident;
 
-    let struct_ = match Struct::try_from(&item) {
-        Ok(struct_) => Entity::Struct(struct_),
-        // SAFETY: this panic is okay because it happens during the compilation
-        // process. As such, the Rust compiler will complain with this error instead
-        // of happening at program runtime.
-        Err(err) => panic!("error: {err}"),
-    };
+    let mut generics = item.generics.clone();
+    let predicates = item
+        .generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            syn::GenericParam::Type(param) => Some(param.ident.to_token_stream()),
+            syn::GenericParam::Lifetime(param) => Some(param.lifetime.to_token_stream()),
+            syn::GenericParam::Const(_) => None,
+        })
+        .map(|param| -> syn::WherePredicate { syn::parse_quote!(#param: 'static) })
+        .collect::<Vec<_>>();
+    generics.make_where_clause().predicates.extend(predicates);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     let fields = item
         .fields
         .iter()
-        .map(|field| match Field::try_from(field) {
-            Ok(field) => field,
-            // SAFETY: this panic is okay because it happens during the compilation
-            // process. As such, the Rust compiler will complain with this error instead
-            // of happening at program runtime.
-            Err(err) => panic!("error: {err}"),
+        .enumerate()
+        .filter(|(_, field)| !skipped(&field.attrs))
+        .map(|(index, field)| {
+            let (name, member) = match field.ident.as_ref() {
+                Some(ident) => (ident.to_string(), ident.to_token_stream()),
+                None => (index.to_string(), syn::Index::from(index).to_token_stream()),
+            };
+
+            let cfgs = cfgs(&field.attrs);
+            (quote!(#(#cfgs)*), name, member, &field.ty)
         })
-        .map(introspect_core::Member::Field)
         .collect::<Vec<_>>();
 
+    let get = fields.iter().map(|(cfgs, name, member, _)| {
+        quote!(#cfgs #name => ::core::option::Option::Some(&self.#member),)
+    });
+    let get_mut = fields.iter().map(|(cfgs, name, member, _)| {
+        quote!(#cfgs #name => ::core::option::Option::Some(&mut self.#member),)
+    });
+    let set = fields.iter().map(|(cfgs, name, member, ty)| {
+        quote!(#cfgs #name => match value.downcast::<#ty>() {
+            ::core::result::Result::Ok(value) => {
+                self.#member = *value;
+                ::core::result::Result::Ok(())
+            }
+            ::core::result::Result::Err(_) => {
+                ::core::result::Result::Err(::introspect::reflect::Error::MismatchedType {
+                    expected: ::core::any::type_name::<#ty>(),
+                })
+            }
+        },)
+    });
+    let values = fields
+        .iter()
+        .map(|(cfgs, _, member, _)| quote!(#cfgs &self.#member as &dyn ::core::any::Any));
+
     quote! {
         #[automatically_derived]
-        impl ::introspect::IntrospectedEntity for #ident {
+        impl #impl_generics ::introspect::Reflect for #ident #ty_generics #where_clause {
+            fn get(&self, name: &str) -> ::core::option::Option<&dyn ::core::any::Any> {
+                match name {
+                    #(#get)*
+                    _ => ::core::option::Option::None,
+                }
+            }
 
-            fn introspected_entity() -> ::introspect::Entity {
-                #struct_
+            fn get_mut(&mut self, name: &str) -> ::core::option::Option<&mut dyn ::core::any::Any> {
+                match name {
+                    #(#get_mut)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            ::introspect::__private::if_alloc! {
+                fn set(
+                    &mut self,
+                    name: &str,
+                    value: ::introspect::__private::Box<dyn ::core::any::Any>,
+                ) -> ::core::result::Result<(), ::introspect::reflect::Error> {
+                    match name {
+                        #(#set)*
+                        _ => {
+                            ::core::mem::drop(value);
+                            ::core::result::Result::Err(::introspect::reflect::Error::UnknownField)
+                        }
+                    }
+                }
+            }
+
+            fn reflected_fields(
+                &self,
+            ) -> impl ::core::iter::Iterator<
+                Item = (&'static ::introspect::r#struct::Field, &dyn ::core::any::Any),
+            > {
+                <Self as ::introspect::IntrospectedStruct>::introspected_fields()
+                    .iter()
+                    .zip([#(#values),*])
             }
         }
+    }
+}
 
-        #[automatically_derived]
-        impl ::introspect::IntrospectedMembers for #ident {
+/// Implements `IntrospectValue` for an entity that opted in via
+/// `#[introspect(value)]` given the expression that converts `self` into a `Value`.
+///
+/// NOTE: each type parameter of the entity must also implement `IntrospectValue`.
+/// Further, values require an allocator, so the implementation is only provided
+/// when the `alloc` feature of `introspect` is enabled.
+fn implement_value(
+    ident: &syn::Ident,
+    generics: &syn::Generics,
+    value: TokenStream,
+) -> TokenStream {
+    let mut generics = generics.clone();
+    let predicates = generics
+        .type_params()
+        .map(|param| -> syn::WherePredicate {
+            let ident = &param.ident;
+            syn::parse_quote!(#ident: ::introspect::IntrospectValue)
+        })
+        .collect::<Vec<_>>();
+    generics.make_where_clause().predicates.extend(predicates);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-            fn introspected_members() -> Vec<::introspect::Member> {
-                vec![
-                    #(#fields),*
-                ]
+    quote! {
+        ::introspect::__private::if_alloc! {
+            #[automatically_derived]
+            impl #impl_generics ::introspect::IntrospectValue for #ident #ty_generics #where_clause {
+                fn introspect_value(&self) -> ::introspect::value::Value {
+                    #value
+                }
             }
         }
+    }
+}
 
-        #[automatically_derived]
-        impl ::introspect::Introspected for #ident {}
+/// Generates an expression that converts a struct into a `Value`.
+fn resolve_struct_value(item: &syn::ItemStruct) -> TokenStream {
+    let values = item
+        .fields
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| !skipped(&field.attrs))
+        .map(|(index, field)| {
+            let cfgs = cfgs(&field.attrs);
+            let member = match field.ident.as_ref() {
+                Some(ident) => ident.to_token_stream(),
+                None => syn::Index::from(index).to_token_stream(),
+            };
+
+            quote!(#(#cfgs)* ::introspect::IntrospectValue::introspect_value(&self.#member))
+        });
+
+    quote! {
+        ::introspect::value::Value::Struct {
+            entity: <Self as ::introspect::IntrospectedStruct>::introspected_struct(),
+            fields: <Self as ::introspect::IntrospectedStruct>::introspected_fields()
+                .iter()
+                .zip([#(#values),*])
+                .collect(),
+        }
+    }
+}
+
+/// Generates an expression that converts an enum into a `Value`.
+///
+/// NOTE: the values of the fields of each variant are collected by matching on
+/// `self`, whereas the introspected variant is resolved via `variant_index()`.
+fn resolve_enum_value(item: &syn::ItemEnum) -> TokenStream {
+    // NOTE: an enum without variants has no instances, and the code following
+    // `introspected_variant()` would be unreachable.
+    if item.variants.is_empty() {
+        return quote!(match *self {});
+    }
+
+    let arms = item.variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        let variant_cfgs = cfgs(&variant.attrs);
+        let variant_cfgs = quote!(#(#variant_cfgs)*);
+
+        if skipped(&variant.attrs) {
+            return quote!(#variant_cfgs Self::#ident { .. } => ::introspect::__private::Vec::new(),);
+        }
+
+        let fields = variant
+            .fields
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| !skipped(&field.attrs))
+            .map(|(index, field)| {
+                let member = match field.ident.as_ref() {
+                    Some(ident) => ident.to_token_stream(),
+                    None => syn::Index::from(index).to_token_stream(),
+                };
+
+                let cfgs = cfgs(&field.attrs);
+                (quote!(#(#cfgs)*), member, format_ident!("__value_{}", index))
+            })
+            .collect::<Vec<_>>();
+
+        let patterns = fields
+            .iter()
+            .map(|(cfgs, member, binding)| quote!(#cfgs #member: ref #binding,));
+        let values = fields.iter().map(|(cfgs, _, binding)| {
+            quote!(#cfgs ::introspect::IntrospectValue::introspect_value(#binding))
+        });
+
+        quote! {
+            #variant_cfgs Self::#ident { #(#patterns)* .. } => {
+                ::introspect::__private::Vec::from([#(#values),*])
+            }
+        }
+    });
+
+    quote! {
+        let variant = match ::introspect::IntrospectedEnum::introspected_variant(self) {
+            ::core::option::Option::Some(variant) => variant,
+            ::core::option::Option::None => return ::introspect::value::Value::Skipped,
+        };
+
+        let values: ::introspect::__private::Vec<::introspect::value::Value> = match *self {
+            #(#arms)*
+        };
+
+        ::introspect::value::Value::Enum {
+            entity: <Self as ::introspect::IntrospectedEnum>::introspected_enum(),
+            variant,
+            payload: variant.fields().iter().zip(values).collect(),
+        }
+    }
+}
+
+/// Generates the match arms that resolve the index of each variant of an enum
+/// within its introspected variants.
+///
+/// NOTE: skipped variants are not introspected, so they resolve to `None`.
+/// Variants whose `#[cfg(..)]` predicates do not hold have already been removed by
+/// the compiler (see [`cfgs()`]), so they are not counted.
+fn resolve_variant_indices(item: &syn::ItemEnum) -> Vec<TokenStream> {
+    let mut index = 0usize;
+
+    item.variants
+        .iter()
+        .map(|variant| {
+            let ident = &variant.ident;
+            let cfgs = cfgs(&variant.attrs);
+
+            match skipped(&variant.attrs) {
+                true => quote!(#(#cfgs)* Self::#ident { .. } => ::core::option::Option::None,),
+                false => {
+                    let arm = quote!(#(#cfgs)* Self::#ident { .. } => ::core::option::Option::Some(#index),);
+                    index += 1;
+                    arm
+                }
+            }
+        })
+        .collect()
+}
+
+/// Generates the introspected fields of a struct, union, or variant, each under the
+/// `#[cfg(..)]` attributes that the field is gated by.
+fn resolve_fields<'a>(
+    fields: impl Iterator<Item = &'a syn::Field>,
+) -> syn::Result<Vec<TokenStream>> {
+    fields
+        .enumerate()
+        .filter(|(_, field)| !skipped(&field.attrs))
+        .map(|(index, field)| {
+            let introspected = Field::try_from((index, field))?;
+            let span = match field.ident.as_ref() {
+                Some(ident) => ident.span(),
+                None => field.ty.span(),
+            };
+            let cfgs = cfgs(&field.attrs);
+            let field = introspected.to_resolved_tokens(&resolve(span, &field.attrs));
+
+            Ok(quote!(#(#cfgs)* #field))
+        })
+        .collect()
+}
+
+/// Resolves the location of the entity or member at the provided span and, if
+/// needed, its documentation (see [`resolve_location()`] and
+/// [`documentation_expression()`]).
+///
+/// [`documentation_expression()`]: introspect_core::parse::documentation_expression
+fn resolve(span: Span, attrs: &[syn::Attribute]) -> Resolved {
+    Resolved {
+        location: Some(resolve_location(span)),
+        documentation: introspect_core::parse::documentation_expression(attrs),
+        documentation_raw: introspect_core::parse::documentation_raw_expression(attrs),
+        ..Default::default()
+    }
+}
+
+/// Generates an expression that resolves the [`Location`](introspect_core::Location)
+/// of the entity or member at the provided span.
+///
+/// NOTE: the expression is spanned such that `line!()` and `column!()` resolve to
+/// the position of the entity or member rather than that of the derive.
+fn resolve_location(span: Span) -> TokenStream {
+    quote_spanned! {span=>
+        ::introspect::location::Location::new(
+            ::introspect::borrow::Str::Borrowed(::core::file!()),
+            ::introspect::borrow::Str::Borrowed(::core::module_path!()),
+            ::core::line!(),
+            ::core::column!(),
+        )
+    }
+}
+
+/// Gets the `#[cfg(..)]` attributes of a member.
+///
+/// NOTE: the compiler typically removes members whose `#[cfg(..)]` predicates do not
+/// hold before the derive macro is invoked. Regardless, each member is emitted
+/// under the same `#[cfg(..)]` attributes as the member itself so that the
+/// introspected members always match those that are compiled.
+fn cfgs(attrs: &[syn::Attribute]) -> impl Iterator<Item = &syn::Attribute> {
+    attrs.iter().filter(|attr| attr.path().is_ident("cfg"))
+}
+
+/// Returns whether a member was marked with `#[introspect(skip)]`.
+fn skipped(attrs: &[syn::Attribute]) -> bool {
+    match Options::try_from(attrs) {
+        Ok(options) => options.skip(),
+        // NOTE: invalid options are reported when the member itself is converted.
+        Err(_) => false,
     }
 }
@@ -1,7 +1,5 @@
-use introspect::Entity;
 use introspect::Introspect;
-use introspect::Introspected;
-use introspect::Member;
+use introspect::IntrospectedEnum;
 
 /// This is the documentation for the [`ExampleOne`] enum.
 ///
@@ -18,6 +16,12 @@ enum ExampleOne {
     ///
     /// And some more text.
     Two,
+
+    /// The third variant, which carries a payload.
+    Three {
+        /// The code for the third variant.
+        code: u32,
+    },
 }
 
 #[allow(dead_code)]
@@ -27,21 +31,40 @@ enum ExampleTwo {
     Two,
 }
 
+/// A C-like enum with explicit and implicit discriminants.
+#[allow(dead_code)]
+#[derive(Introspect)]
+#[repr(u8)]
+enum ExampleThree {
+    /// Starts at four.
+    Four = 4,
+
+    /// Implicitly follows the previous variant.
+    Five,
+
+    /// Computed from a constant expression.
+    Eight = 1 << 3,
+}
+
 pub fn main() {
     print_enum::<ExampleOne>();
 
     println!();
 
     print_enum::<ExampleTwo>();
+
+    println!();
+
+    print_enum::<ExampleThree>();
 }
 
-fn print_enum<S: Introspected>() {
-    let enum_ = match S::introspected_entity() {
-        Entity::Enum(enum_) => enum_,
-        _ => unreachable!(),
-    };
+fn print_enum<S: IntrospectedEnum>() {
+    let enum_ = S::introspected_enum();
 
-    println!("[{}]\n", enum_.identifier());
+    match enum_.repr() {
+        Some(repr) => println!("[{} as {}]\n", enum_.identifier(), repr),
+        None => println!("[{}]\n", enum_.identifier()),
+    }
 
     if let Some(documentation) = enum_.documentation() {
         println!(
@@ -55,25 +78,33 @@ fn print_enum<S: Introspected>() {
         )
     }
 
-    for variant in S::introspected_members() {
-        match variant {
-            Member::Variant(variant) => {
-                print!("* {}", variant.identifier());
-
-                if let Some(doc) = variant.documentation() {
-                    print!(
-                        ": {}",
-                        doc.lines()
-                            .map(|line| line.trim())
-                            .filter(|line| !line.is_empty())
-                            .collect::<Vec<_>>()
-                            .join(" ")
-                    )
-                }
-
-                println!();
-            }
-            _ => unreachable!(),
+    for variant in S::introspected_variants() {
+        print!("* {}", variant.identifier());
+
+        if let Some(value) = variant.discriminant_value() {
+            print!(" = {}", value);
+        }
+
+        if let Some(doc) = variant.documentation() {
+            print!(
+                ": {}",
+                doc.lines()
+                    .map(|line| line.trim())
+                    .filter(|line| !line.is_empty())
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            )
+        }
+
+        println!();
+
+        for field in variant.fields() {
+            let ident = field
+                .identifier()
+                .map(|s| s.to_owned())
+                .unwrap_or(format!(".{}", field.index()));
+
+            println!("  - {}: {}", ident, field.ty().text());
         }
     }
 }
@@ -1,39 +1,81 @@
-use introspect::Entity;
+use introspect::generics::GenericParam;
+use introspect::metadata::Value;
 use introspect::Introspect;
-use introspect::IntrospectedEntity;
-use introspect::IntrospectedMembers;
-use introspect::Member;
+use introspect::IntrospectedStruct;
 
 /// This is the documentation for the [`ExampleOne`] struct.
 #[allow(dead_code)]
 #[derive(Debug, Introspect)]
 struct ExampleOne {
     /// A foo.
-    foo: String,
+    #[introspect(meta(unit = "ms", min = 0, max = 65535, experimental = true))]
+    pub foo: String,
 
     /// A bar.
     ///
     /// This bar always confroms to baz.
+    #[introspect(rename = "baz")]
     bar: usize,
+
+    /// Internal bookkeeping that is not introspected.
+    #[introspect(skip)]
+    bookkeeping: u64,
 }
 
+#[allow(dead_code)]
 #[derive(Introspect)]
 struct ExampleTwo(usize);
 
+/// A generic wrapper around a borrowed value.
+#[allow(dead_code)]
+#[derive(Introspect)]
+struct ExampleThree<'a, T: Clone, const N: usize>
+where
+    T: Default,
+{
+    /// The borrowed values.
+    values: &'a [T; N],
+}
+
 pub fn main() {
     print_struct::<ExampleOne>();
     println!();
 
     print_struct::<ExampleTwo>();
+    println!();
+
+    print_struct::<ExampleThree<'static, u8, 4>>();
 }
 
-fn print_struct<S: IntrospectedMembers + IntrospectedEntity>() {
-    let struct_ = match S::introspected_entity() {
-        Entity::Struct(struct_) => struct_,
-        _ => unreachable!(),
-    };
+fn print_struct<S: IntrospectedStruct>() {
+    let struct_ = S::introspected_struct();
+
+    let params = struct_
+        .generics()
+        .params()
+        .iter()
+        .map(|param| match param {
+            GenericParam::Lifetime { identifier, .. } => identifier.as_ref(),
+            GenericParam::Type { identifier, .. } => identifier.as_ref(),
+            GenericParam::Const { identifier, .. } => identifier.as_ref(),
+        })
+        .collect::<Vec<_>>();
+
+    match params.is_empty() {
+        true => println!("[{}]", struct_.identifier()),
+        false => println!("[{}<{}>]", struct_.identifier(), params.join(", ")),
+    }
+
+    if let Some(location) = struct_.location() {
+        println!(
+            "defined in `{}` at {}:{}",
+            location.module_path(),
+            location.file(),
+            location.line()
+        );
+    }
 
-    println!("[{}]\n", struct_.identifier());
+    println!();
 
     if let Some(documentation) = struct_.documentation() {
         println!(
@@ -47,30 +89,49 @@ fn print_struct<S: IntrospectedMembers + IntrospectedEntity>() {
         )
     }
 
-    for field in S::introspected_members() {
-        match field {
-            Member::Field(field) => {
-                let ident = field
-                    .identifier()
-                    .map(|s| s.to_owned())
-                    .unwrap_or(String::from("<unnamed>"));
-
-                print!("* {}", ident);
-
-                if let Some(doc) = field.documentation() {
-                    print!(
-                        ": {}",
-                        doc.lines()
-                            .map(|line| line.trim())
-                            .filter(|line| !line.is_empty())
-                            .collect::<Vec<_>>()
-                            .join(" ")
-                    )
-                }
-
-                println!();
-            }
-            _ => unreachable!(),
+    for field in S::introspected_fields() {
+        let ident = field
+            .name()
+            .map(|s| s.to_owned())
+            .unwrap_or(format!(".{}", field.index()));
+
+        let vis = match field.visibility().is_public() {
+            true => "pub ",
+            false => "",
+        };
+
+        print!("* {}{}: {}", vis, ident, field.ty().text());
+
+        if let Some(location) = field.location() {
+            print!(" [line {}]", location.line());
         }
+
+        if !field.metadata().is_empty() {
+            let metadata = field
+                .metadata()
+                .iter()
+                .map(|(key, value)| match value {
+                    Value::String(value) => format!("{key} = {value:?}"),
+                    Value::Int(value) => format!("{key} = {value}"),
+                    Value::Float(value) => format!("{key} = {value}"),
+                    Value::Bool(value) => format!("{key} = {value}"),
+                })
+                .collect::<Vec<_>>();
+
+            print!(" ({})", metadata.join(", "));
+        }
+
+        if let Some(doc) = field.documentation() {
+            print!(
+                " - {}",
+                doc.lines()
+                    .map(|line| line.trim())
+                    .filter(|line| !line.is_empty())
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            )
+        }
+
+        println!();
     }
 }
@@ -0,0 +1,51 @@
+use introspect::Introspect;
+use introspect::IntrospectedUnion;
+
+/// A value that is shared with C code.
+///
+/// The active field is determined by a separate tag.
+#[allow(dead_code)]
+#[repr(C)]
+#[derive(Introspect)]
+union ExampleOne {
+    /// The value as an integer.
+    integer: u32,
+
+    /// The value as a floating point number.
+    float: f32,
+
+    /// The raw bytes of the value.
+    bytes: [u8; 4],
+}
+
+pub fn main() {
+    print_union::<ExampleOne>();
+}
+
+fn print_union<U: IntrospectedUnion>() {
+    let union_ = U::introspected_union();
+
+    println!("[{}]\n", union_.identifier());
+
+    if let Some(documentation) = union_.documentation() {
+        println!(
+            "{}\n",
+            documentation
+                .lines()
+                .map(|line| line.trim())
+                .filter(|line| !line.is_empty())
+                .collect::<Vec<_>>()
+                .join(" ")
+        )
+    }
+
+    for field in U::introspected_fields() {
+        print!("* {}: {}", field.name().unwrap(), field.ty().text());
+
+        if let Some(doc) = field.documentation() {
+            print!(" - {}", doc)
+        }
+
+        println!();
+    }
+}
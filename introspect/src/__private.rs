@@ -0,0 +1,90 @@
+//! Helpers for the code generated by the `Introspect` derive macro.
+//!
+//! **Note:** these are not part of the public API and may change at any time.
+
+use crate::borrow::Str;
+
+#[cfg(feature = "alloc")]
+pub use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+pub use alloc::vec::Vec;
+
+/// Expands to the provided tokens only if the `alloc` feature of `introspect` is
+/// enabled.
+///
+/// NOTE: the features of `introspect` cannot be checked by the derive macro, so
+/// generated items that require an allocator are wrapped in this macro instead.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __if_alloc {
+    ($($tokens:tt)*) => { $($tokens)* };
+}
+
+/// Expands to the provided tokens only if the `alloc` feature of `introspect` is
+/// enabled.
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __if_alloc {
+    ($($tokens:tt)*) => {};
+}
+
+pub use crate::__if_alloc as if_alloc;
+
+/// Gets the length of the provided parts once they are joined by newlines.
+pub const fn joined_len(parts: &[&str]) -> usize {
+    let mut len = 0;
+    let mut i = 0;
+
+    while i < parts.len() {
+        if i > 0 {
+            len += 1;
+        }
+
+        len += parts[i].len();
+        i += 1;
+    }
+
+    len
+}
+
+/// Joins the provided parts by newlines.
+///
+/// `N` must be the [`joined_len()`] of the parts.
+pub const fn join<const N: usize>(parts: &[&str]) -> [u8; N] {
+    let mut bytes = [0; N];
+    let mut offset = 0;
+    let mut i = 0;
+
+    while i < parts.len() {
+        if i > 0 {
+            bytes[offset] = b'\n';
+            offset += 1;
+        }
+
+        let part = parts[i].as_bytes();
+        let mut j = 0;
+
+        while j < part.len() {
+            bytes[offset] = part[j];
+            offset += 1;
+            j += 1;
+        }
+
+        i += 1;
+    }
+
+    bytes
+}
+
+/// Converts the [`join()`]ed documentation into a [`Str`], unless the
+/// documentation is empty.
+pub const fn documentation(bytes: &'static [u8]) -> Option<Str> {
+    match core::str::from_utf8(bytes) {
+        Ok(documentation) if documentation.is_empty() => None,
+        Ok(documentation) => Some(Str::Borrowed(documentation)),
+        // NOTE: joining valid UTF-8 strings by newlines is always valid UTF-8.
+        Err(_) => unreachable!(),
+    }
+}
@@ -0,0 +1,4 @@
+//! Rust attributes.
+
+pub use introspect_core::attribute::Attribute;
+pub use introspect_core::attribute::Meta;
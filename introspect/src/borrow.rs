@@ -0,0 +1,4 @@
+//! Strings and slices that are borrowed for the `'static` lifetime.
+
+pub use introspect_core::borrow::Slice;
+pub use introspect_core::borrow::Str;
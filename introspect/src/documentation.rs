@@ -0,0 +1,7 @@
+//! Structured documentation.
+
+pub use introspect_core::documentation::CodeBlock;
+pub use introspect_core::documentation::CodeBlocks;
+pub use introspect_core::documentation::Documentation;
+pub use introspect_core::documentation::Section;
+pub use introspect_core::documentation::Sections;
@@ -1,3 +1,7 @@
 //! Rust enums.
 
+pub mod variant;
+
+pub use introspect_core::r#enum::Parts;
 pub use introspect_core::r#enum::Variant;
+pub use introspect_core::r#enum::VariantKind;
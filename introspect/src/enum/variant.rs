@@ -0,0 +1,3 @@
+//! Rust enum variants.
+
+pub use introspect_core::r#enum::variant::Parts;
@@ -0,0 +1,5 @@
+//! Rust generics.
+
+pub use introspect_core::generics::GenericParam;
+pub use introspect_core::generics::Generics;
+pub use introspect_core::generics::WherePredicate;
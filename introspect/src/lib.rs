@@ -1,11 +1,14 @@
-//! Introspection for Rust `struct`s and `enum`s ("entities") and their respective
-//! fields and variants ("members").
+//! Introspection for Rust `struct`s, `enum`s, and `union`s ("entities") and their
+//! respective fields and variants ("members").
 //!
-//! **Note:** currently, only the identifier name and the optional documentation for
-//! each supported entity and member are implemented. That was all that was needed at
-//! the time the crate was developed. However, we will implement requests and/or accept
-//! pull requests that add additional introspection—please just leave an issue on the
-//! [issues page](https://github.com/claymcleod/introspect/issues)!
+//! Along with the identifier and documentation of each entity and member, the
+//! introspection covers the fields of structs and unions (including their types
+//! and visibility), the variants of enums (including their payload fields and
+//! discriminants), generics, source locations, attributes, and user-defined
+//! metadata. Structs can also opt in to reflection at runtime, and structs and enums
+//! to conversion into self-describing values. If you need introspection that is not
+//! yet supported, please leave an issue on the [issues
+//! page](https://github.com/claymcleod/introspect/issues)!
 //!
 //! ## Getting Started
 //!
@@ -22,11 +25,8 @@
 //! macro like so.
 //!
 //! ```rust
-//! use introspect::Entity;
 //! use introspect::Introspect;
-//! use introspect::IntrospectedEntity;
-//! use introspect::IntrospectedMembers;
-//! use introspect::Member;
+//! use introspect::IntrospectedEnum;
 //!
 //! /// This is the documentation for the [`Example`] enum.
 //! ///
@@ -44,58 +44,536 @@
 //! }
 //!
 //! // Access to the top-level entity characteristics.
-//! match Example::introspected_entity() {
-//!     Entity::Enum(entity) => {
-//!         dbg!(entity.identifier());
-//!         dbg!(entity.documentation());
-//!     }
-//!     _ => unreachable!(),
-//! }
+//! let entity = Example::introspected_enum();
+//! dbg!(entity.identifier());
+//! dbg!(entity.documentation());
 //!
-//! // Access to the members of the entity.
-//! for member in Example::introspected_members() {
-//!     match member {
-//!         Member::Variant(member) => {
-//!             dbg!(member.identifier());
-//!             dbg!(member.documentation());
-//!         }
-//!         _ => unreachable!(),
-//!     }
+//! // Access to the variants of the entity.
+//! for variant in entity.variants() {
+//!     dbg!(variant.identifier());
+//!     dbg!(variant.documentation());
 //! }
 //! ```
 //!
+//! When the kind of entity is known, the [`IntrospectedStruct`],
+//! [`IntrospectedEnum`], and [`IntrospectedUnion`] traits (also implemented by the
+//! [`Introspect`] derive macro) provide the [`Struct`], [`Enum`], or [`Union`] and
+//! its fields or variants directly.
+//!
+//! Structs that opt in via `#[introspect(reflect)]` additionally implement the
+//! [`Reflect`] trait, which gets and sets their fields by identifier at runtime.
+//! Similarly, with the `alloc` feature, structs and enums that opt in via
+//! `#[introspect(value)]` implement the `IntrospectValue` trait, which converts an
+//! instance into a self-describing `value::Value` tree (e.g., for generic
+//! pretty-printing, diffing, or export).
+//!
 //! You can also take a look at the
 //! [examples](https://github.com/claymcleod/introspect/tree/main/introspect/examples) to
 //! get a sense of the various ways you can use the crate.
+//!
+//! ## Documentation
+//!
+//! Documentation is captured from doc comments and `#[doc = ".."]` attributes.
+//! Documentation may also be provided via macro calls, such as `#[doc =
+//! include_str!("../docs/config.md")]` or `#[doc = concat!(..)]`: these are
+//! evaluated when compiling your crate, and their expansion is included verbatim.
+//!
+//! As with rustdoc, the common leading whitespace is stripped from the
+//! documentation (as is the decoration of `/** .. */` block comments), so the
+//! relative indentation of nested lists and code blocks is preserved. The text of
+//! each attribute exactly as written is available via methods such as
+//! [`Struct::documentation_raw()`].
+//!
+//! The documentation can also be parsed into a summary, a body, and sections (such
+//! as `# Examples`) with their code blocks via methods such as
+//! [`Struct::documentation_parsed()`] (see [`Documentation`]).
+//!
+//! ## Conditional Compilation
+//!
+//! The introspected entities and members match what is actually compiled. Fields
+//! and variants under `#[cfg(..)]` are only introspected when they are compiled, and
+//! documentation within `#[cfg_attr(.., doc = "..")]` is only included when the
+//! predicate holds.
+//!
+//! ## Options
+//!
+//! The [`Introspect`] derive macro accepts options via the `#[introspect(..)]` helper
+//! attribute on entities, fields, and variants.
+//!
+//! * `rename = "..."`: the public name shown in the introspected metadata (available
+//!   via methods such as [`Struct::name()`]).
+//! * `hidden`: keeps the entity or member but flags it as hidden (available via
+//!   methods such as [`Struct::is_hidden()`]).
+//! * `skip` (fields and variants only): omits the member from
+//!   [`IntrospectedMembers::introspected_members()`] entirely.
+//! * `reflect` (structs only): implements [`Reflect`] for the struct.
+//! * `value` (structs and enums only): implements `IntrospectValue` for the entity
+//!   (requires the `alloc` feature).
+//! * `meta(key = value, ..)`: attaches typed, user-defined [`Metadata`] (available
+//!   via methods such as [`Struct::metadata()`]). Each value must be a string,
+//!   integer, float, or boolean literal.
+//!
+//! ## Features
+//!
+//! * `derive` (enabled by default): provides the [`Introspect`] derive macro.
+//! * `std` (enabled by default): enables the `std` feature of `introspect-core`.
+//!   Implies `alloc`.
+//! * `alloc`: allows the model types to own their data (e.g., via
+//!   [`Metadata::insert()`]) and enables `Reflect::set()` and `IntrospectValue`.
+//! * `attributes`: captures the attributes (other than documentation) of each
+//!   entity and member, which are then available via methods such as
+//!   [`Struct::attributes()`]. Only has an effect along with `derive`.
+//!
+//! Parsing entities from their syntax trees (and generating the tokens that embed
+//! them) only happens within the [`Introspect`] derive macro, so `syn`, `quote`,
+//! and `proc-macro2` are never compiled into the crates that use `introspect`.
+//! Without the `derive` feature, they are not compiled at all.
+//!
+//! ## `no_std`
+//!
+//! Without the `std` and `alloc` features, the crate is `#![no_std]` and does not
+//! require an allocator. The [`Introspect`] derive macro embeds all introspected
+//! data within the binary as `'static` constants, so the introspection traits work
+//! the same on embedded targets.
+//!
+//! ```toml
+//! introspect = { version = "0.1", default-features = false, features = ["derive"] }
+//! ```
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![warn(missing_docs)]
 #![warn(rust_2018_idioms)]
 #![warn(rust_2021_compatibility)]
 #![warn(missing_debug_implementations)]
 #![warn(rustdoc::broken_intra_doc_links)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub mod attribute;
+pub mod borrow;
+pub mod documentation;
 pub mod r#enum;
+pub mod generics;
+pub mod location;
+pub mod metadata;
+pub mod reflect;
 pub mod r#struct;
+pub mod r#type;
+pub mod union;
+#[cfg(feature = "alloc")]
+pub mod value;
+pub mod visibility;
+
+#[doc(hidden)]
+pub mod __private;
 
 #[cfg(feature = "derive")]
 pub use introspect_proc_macros::Introspect;
 
+pub use introspect_core::Attribute;
+pub use introspect_core::Documentation;
 pub use introspect_core::Entity;
+pub use introspect_core::EntityDescriptor;
 pub use introspect_core::Enum;
+pub use introspect_core::Generics;
+pub use introspect_core::Location;
 pub use introspect_core::Member;
+pub use introspect_core::Members;
+pub use introspect_core::Metadata;
 pub use introspect_core::Struct;
+pub use introspect_core::TypeRef;
+pub use introspect_core::Union;
+pub use introspect_core::Visibility;
 
 /// A trait that provides introspection of a Rust entity.
 pub trait IntrospectedEntity {
     /// Gets the introspected entity.
-    fn introspected_entity() -> Entity;
+    fn introspected_entity() -> &'static Entity;
 }
 
 /// A trait that provides introspection of a Rust entity's members.
 pub trait IntrospectedMembers {
-    /// Gets the introspected entity's members.
-    fn introspected_members() -> Vec<Member>;
+    /// Gets an iterator over the introspected entity's members.
+    fn introspected_members() -> Members<'static>;
+
+    /// Gets an iterator over the introspected entity's public members.
+    ///
+    /// Fields are only included when they are declared `pub`, whereas variants
+    /// are always included (as they share the visibility of their enum).
+    fn introspected_public_members() -> impl Iterator<Item = Member<'static>> {
+        Self::introspected_members().filter(|member| member.is_public())
+    }
+
+    /// Gets an iterator over the introspected entity's members that are not hidden
+    /// via `#[introspect(hidden)]`.
+    fn introspected_visible_members() -> impl Iterator<Item = Member<'static>> {
+        Self::introspected_members().filter(|member| !member.is_hidden())
+    }
 }
 
 /// A trait encompassing all introspection supported by the crate.
-pub trait Introspected: IntrospectedEntity + IntrospectedMembers {}
+pub trait Introspected: IntrospectedEntity + IntrospectedMembers {
+    /// The introspected entity and its members.
+    ///
+    /// This is embedded within the binary by the [`Introspect`] derive macro, so
+    /// accessing it never allocates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect::Entity;
+    /// use introspect::Introspect;
+    /// use introspect::Introspected;
+    ///
+    /// #[allow(dead_code)]
+    /// #[derive(Introspect)]
+    /// struct Config<T> {
+    ///     /// The timeout.
+    ///     timeout: T,
+    /// }
+    ///
+    /// let descriptor = <Config<u64> as Introspected>::INTROSPECTION;
+    ///
+    /// assert!(matches!(descriptor.entity(), Entity::Struct(struct_) if struct_.identifier() == "Config"));
+    /// assert_eq!(descriptor.members().len(), 1);
+    /// ```
+    const INTROSPECTION: &'static EntityDescriptor;
+}
+
+/// A trait that provides introspection of a Rust struct.
+///
+/// Unlike [`IntrospectedEntity`], the kind of entity is known at compile time, so
+/// generic code can bound on `T: IntrospectedStruct` without matching on
+/// [`Entity`].
+///
+/// # Examples
+///
+/// ```
+/// use introspect::Introspect;
+/// use introspect::IntrospectedStruct;
+///
+/// #[allow(dead_code)]
+/// #[derive(Introspect)]
+/// struct Config {
+///     /// The port.
+///     port: u16,
+/// }
+///
+/// fn field_names<T: IntrospectedStruct>() -> Vec<&'static str> {
+///     T::introspected_fields()
+///         .iter()
+///         .filter_map(|field| field.identifier())
+///         .collect()
+/// }
+///
+/// assert_eq!(Config::introspected_struct().identifier(), "Config");
+/// assert_eq!(field_names::<Config>(), ["port"]);
+/// ```
+pub trait IntrospectedStruct: Introspected {
+    /// Gets the introspected struct.
+    fn introspected_struct() -> &'static Struct;
+
+    /// Gets the introspected struct's fields.
+    fn introspected_fields() -> &'static [r#struct::Field] {
+        Self::introspected_struct().fields()
+    }
+}
+
+/// A trait that provides introspection of a Rust enum.
+///
+/// Unlike [`IntrospectedEntity`], the kind of entity is known at compile time, so
+/// generic code can bound on `T: IntrospectedEnum` without matching on [`Entity`].
+///
+/// # Examples
+///
+/// ```
+/// use introspect::Introspect;
+/// use introspect::IntrospectedEnum;
+///
+/// #[allow(dead_code)]
+/// #[derive(Introspect)]
+/// enum Mode {
+///     /// Fast.
+///     Fast,
+///
+///     /// Slow.
+///     Slow,
+/// }
+///
+/// fn variant_names<T: IntrospectedEnum>() -> Vec<&'static str> {
+///     T::introspected_variants()
+///         .iter()
+///         .map(|variant| variant.identifier())
+///         .collect()
+/// }
+///
+/// assert_eq!(Mode::introspected_enum().identifier(), "Mode");
+/// assert_eq!(variant_names::<Mode>(), ["Fast", "Slow"]);
+/// ```
+pub trait IntrospectedEnum: Introspected {
+    /// Gets the introspected enum.
+    fn introspected_enum() -> &'static Enum;
+
+    /// Gets the introspected enum's variants.
+    fn introspected_variants() -> &'static [r#enum::Variant] {
+        Self::introspected_enum().variants()
+    }
+
+    /// Gets the index of this value's variant within
+    /// [`introspected_variants()`](IntrospectedEnum::introspected_variants).
+    ///
+    /// Returns `None` if the variant was skipped via `#[introspect(skip)]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect::Introspect;
+    /// use introspect::IntrospectedEnum;
+    ///
+    /// #[allow(dead_code)]
+    /// #[derive(Introspect)]
+    /// enum Shape {
+    ///     Point,
+    ///     #[introspect(skip)]
+    ///     Empty,
+    ///     Circle { radius: f64 },
+    /// }
+    ///
+    /// assert_eq!(Shape::Point.variant_index(), Some(0));
+    /// assert_eq!(Shape::Empty.variant_index(), None);
+    /// assert_eq!(Shape::Circle { radius: 1.0 }.variant_index(), Some(1));
+    /// ```
+    fn variant_index(&self) -> Option<usize>;
+
+    /// Gets the introspected variant of this value.
+    ///
+    /// Returns `None` if the variant was skipped via `#[introspect(skip)]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect::Introspect;
+    /// use introspect::IntrospectedEnum;
+    ///
+    /// #[allow(dead_code)]
+    /// #[derive(Debug, Introspect)]
+    /// enum Error {
+    ///     /// The file could not be found.
+    ///     NotFound(String),
+    ///
+    ///     /// Permission to the file was denied.
+    ///     PermissionDenied,
+    /// }
+    ///
+    /// let err = Error::NotFound(String::from("config.toml"));
+    /// let variant = err.introspected_variant().unwrap();
+    ///
+    /// assert_eq!(variant.identifier(), "NotFound");
+    /// assert_eq!(variant.documentation(), Some("The file could not be found."));
+    /// ```
+    fn introspected_variant(&self) -> Option<&'static r#enum::Variant> {
+        self.variant_index()
+            .map(|index| &Self::introspected_variants()[index])
+    }
+}
+
+/// A trait that provides introspection of a Rust union.
+///
+/// Unlike [`IntrospectedEntity`], the kind of entity is known at compile time, so
+/// generic code can bound on `T: IntrospectedUnion` without matching on [`Entity`].
+///
+/// # Examples
+///
+/// ```
+/// use introspect::Introspect;
+/// use introspect::IntrospectedUnion;
+///
+/// #[allow(dead_code)]
+/// #[derive(Introspect)]
+/// union Bits {
+///     /// As an integer.
+///     integer: u32,
+///
+///     /// As a float.
+///     float: f32,
+/// }
+///
+/// assert_eq!(Bits::introspected_union().identifier(), "Bits");
+/// assert_eq!(Bits::introspected_fields()[1].identifier(), Some("float"));
+/// ```
+pub trait IntrospectedUnion: Introspected {
+    /// Gets the introspected union.
+    fn introspected_union() -> &'static Union;
+
+    /// Gets the introspected union's fields.
+    fn introspected_fields() -> &'static [r#struct::Field] {
+        Self::introspected_union().fields()
+    }
+}
+
+/// A trait that provides dynamic access to the fields of a Rust struct by
+/// identifier.
+///
+/// Fields are accessed by the same identifiers that [`Field::identifier()`](crate::struct::Field::identifier)
+/// provides, whereas the fields of tuple structs are accessed by their index (e.g.,
+/// `"0"`). Skipped fields cannot be accessed.
+///
+/// The [`Introspect`] derive macro implements this trait for structs that opt in
+/// via `#[introspect(reflect)]`. Every field that is not skipped must be `'static`.
+///
+/// # Examples
+///
+/// ```
+/// use introspect::Introspect;
+/// use introspect::Reflect;
+///
+/// #[derive(Introspect)]
+/// #[introspect(reflect)]
+/// struct Config {
+///     /// The port.
+///     port: u16,
+///
+///     /// The host.
+///     host: String,
+/// }
+///
+/// let mut config = Config {
+///     port: 80,
+///     host: String::from("localhost"),
+/// };
+///
+/// assert_eq!(config.get("port").and_then(|port| port.downcast_ref::<u16>()), Some(&80));
+///
+/// if let Some(port) = config.get_mut("port").and_then(|port| port.downcast_mut::<u16>()) {
+///     *port = 8080;
+/// }
+///
+/// for (field, value) in config.reflected_fields() {
+///     match field.identifier() {
+///         Some("port") => assert_eq!(value.downcast_ref::<u16>(), Some(&8080)),
+///         Some("host") => assert_eq!(value.downcast_ref::<String>().unwrap(), "localhost"),
+///         _ => unreachable!(),
+///     }
+/// }
+/// ```
+pub trait Reflect: IntrospectedStruct {
+    /// Gets the field with the provided identifier, if it exists.
+    fn get(&self, name: &str) -> Option<&dyn core::any::Any>;
+
+    /// Gets the field with the provided identifier mutably, if it exists.
+    fn get_mut(&mut self, name: &str) -> Option<&mut dyn core::any::Any>;
+
+    /// Sets the field with the provided identifier to the provided value.
+    ///
+    /// Fails if no field has the provided identifier or if the value does not have
+    /// the type of the field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use introspect::reflect::Error;
+    /// use introspect::Introspect;
+    /// use introspect::Reflect;
+    ///
+    /// #[derive(Introspect)]
+    /// #[introspect(reflect)]
+    /// struct Config {
+    ///     /// The host.
+    ///     host: String,
+    /// }
+    ///
+    /// let mut config = Config {
+    ///     host: String::from("localhost"),
+    /// };
+    ///
+    /// config.set("host", Box::new(String::from("example.com")))?;
+    /// assert_eq!(config.host, "example.com");
+    ///
+    /// assert!(matches!(
+    ///     config.set("host", Box::new(42u8)),
+    ///     Err(Error::MismatchedType { .. })
+    /// ));
+    /// assert!(matches!(
+    ///     config.set("missing", Box::new(42u8)),
+    ///     Err(Error::UnknownField)
+    /// ));
+    ///
+    /// # Ok::<(), Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn set(
+        &mut self,
+        name: &str,
+        value: alloc::boxed::Box<dyn core::any::Any>,
+    ) -> Result<(), reflect::Error>;
+
+    /// Gets an iterator over the introspected fields and their values.
+    fn reflected_fields(
+        &self,
+    ) -> impl Iterator<Item = (&'static r#struct::Field, &dyn core::any::Any)>;
+}
+
+/// A trait that converts an instance of a type into a self-describing
+/// [`value::Value`] tree.
+///
+/// The [`Introspect`] derive macro implements this trait for structs and enums
+/// that opt in via `#[introspect(value)]`. Every field that is not skipped must
+/// also implement [`IntrospectValue`], which is implemented for the primitive types,
+/// [`String`], [`Option`], [`Vec`], slices,
+/// arrays, and maps.
+///
+/// # Examples
+///
+/// ```
+/// use introspect::value::Value;
+/// use introspect::Introspect;
+/// use introspect::IntrospectValue;
+///
+/// #[derive(Introspect)]
+/// #[introspect(value)]
+/// enum Mode {
+///     /// Fast.
+///     Fast { speed: u8 },
+///
+///     /// Slow.
+///     Slow,
+/// }
+///
+/// #[derive(Introspect)]
+/// #[introspect(value)]
+/// struct Config {
+///     /// The port.
+///     port: u16,
+///
+///     /// The mode.
+///     mode: Mode,
+/// }
+///
+/// let config = Config {
+///     port: 80,
+///     mode: Mode::Fast { speed: 3 },
+/// };
+///
+/// match config.introspect_value() {
+///     Value::Struct { entity, fields } => {
+///         assert_eq!(entity.identifier(), "Config");
+///         assert_eq!(fields[0].0.identifier(), Some("port"));
+///         assert_eq!(fields[0].1, Value::UInt(80));
+///
+///         match &fields[1].1 {
+///             Value::Enum { variant, payload, .. } => {
+///                 assert_eq!(variant.identifier(), "Fast");
+///                 assert_eq!(payload[0].1, Value::UInt(3));
+///             }
+///             _ => unreachable!(),
+///         }
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
+#[cfg(feature = "alloc")]
+pub trait IntrospectValue {
+    /// Converts this value into a self-describing [`value::Value`].
+    fn introspect_value(&self) -> value::Value;
+}
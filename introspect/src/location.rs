@@ -0,0 +1,3 @@
+//! Source locations.
+
+pub use introspect_core::location::Location;
@@ -0,0 +1,4 @@
+//! User-defined metadata.
+
+pub use introspect_core::metadata::Metadata;
+pub use introspect_core::metadata::Value;
@@ -0,0 +1,28 @@
+//! Dynamic access to the fields of a struct (see [`Reflect`](crate::Reflect)).
+
+/// An error when setting a field via [`Reflect::set()`](crate::Reflect::set).
+#[derive(Debug)]
+pub enum Error {
+    /// No reflected field has the provided identifier.
+    UnknownField,
+
+    /// The provided value does not have the type of the field.
+    MismatchedType {
+        /// The name of the field's type.
+        expected: &'static str,
+    },
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::UnknownField => write!(f, "unknown field"),
+            Error::MismatchedType { expected } => {
+                write!(f, "mismatched type: expected a value of type `{expected}`")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
@@ -1,3 +1,7 @@
 //! Rust structs.
 
+pub mod field;
+
 pub use introspect_core::r#struct::Field;
+pub use introspect_core::r#struct::Parts;
+pub use introspect_core::r#struct::StructKind;
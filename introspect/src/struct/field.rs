@@ -0,0 +1,3 @@
+//! Rust struct fields.
+
+pub use introspect_core::r#struct::field::Parts;
@@ -0,0 +1,7 @@
+//! Rust types.
+
+pub use introspect_core::r#type::GenericArgument;
+pub use introspect_core::r#type::Kind;
+pub use introspect_core::r#type::Nested;
+pub use introspect_core::r#type::PathSegment;
+pub use introspect_core::r#type::TypeRef;
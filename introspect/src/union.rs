@@ -0,0 +1,3 @@
+//! Rust unions.
+
+pub use introspect_core::union::Parts;
@@ -0,0 +1,239 @@
+//! Self-describing values (see [`IntrospectValue`]).
+
+use alloc::borrow::ToOwned;
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::r#enum::Variant;
+use crate::r#struct::Field;
+use crate::Enum;
+use crate::IntrospectValue;
+use crate::Struct;
+
+/// A self-describing value that is converted from an instance of a type via
+/// [`IntrospectValue`].
+#[derive(Clone, Debug)]
+pub enum Value {
+    /// The unit value, `()`.
+    Unit,
+
+    /// A boolean value.
+    Bool(bool),
+
+    /// A signed integer value.
+    Int(i128),
+
+    /// An unsigned integer value.
+    UInt(u128),
+
+    /// A floating point value.
+    Float(f64),
+
+    /// A character value.
+    Char(char),
+
+    /// A string value.
+    String(String),
+
+    /// An optional value.
+    Option(Option<Box<Value>>),
+
+    /// A sequence of values, such as a [`Vec`], slice, or array.
+    Seq(Vec<Value>),
+
+    /// A map of keys to values (in the iteration order of the map).
+    Map(Vec<(Value, Value)>),
+
+    /// An instance of an introspected struct and the values of its fields.
+    ///
+    /// Skipped fields are not included.
+    Struct {
+        /// The introspected struct.
+        entity: &'static Struct,
+
+        /// The introspected fields and their values.
+        fields: Vec<(&'static Field, Value)>,
+    },
+
+    /// An instance of an introspected enum and the values of its variant's fields.
+    ///
+    /// Skipped fields are not included.
+    Enum {
+        /// The introspected enum.
+        entity: &'static Enum,
+
+        /// The introspected variant.
+        variant: &'static Variant,
+
+        /// The introspected fields of the variant and their values.
+        payload: Vec<(&'static Field, Value)>,
+    },
+
+    /// An instance of a variant that was skipped via `#[introspect(skip)]`.
+    Skipped,
+}
+
+/// Gets whether the introspected fields and their values are equal.
+///
+/// NOTE: the introspected fields are compared by position and identifier, as the
+/// model types do not implement [`PartialEq`].
+fn fields_eq(a: &[(&'static Field, Value)], b: &[(&'static Field, Value)]) -> bool {
+    a.len() == b.len()
+        && a.iter().zip(b).all(|((a, a_value), (b, b_value))| {
+            a.index() == b.index() && a.identifier() == b.identifier() && a_value == b_value
+        })
+}
+
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Value::Unit, Value::Unit) | (Value::Skipped, Value::Skipped) => true,
+            (Value::Bool(a), Value::Bool(b)) => a == b,
+            (Value::Int(a), Value::Int(b)) => a == b,
+            (Value::UInt(a), Value::UInt(b)) => a == b,
+            (Value::Float(a), Value::Float(b)) => a == b,
+            (Value::Char(a), Value::Char(b)) => a == b,
+            (Value::String(a), Value::String(b)) => a == b,
+            (Value::Option(a), Value::Option(b)) => a == b,
+            (Value::Seq(a), Value::Seq(b)) => a == b,
+            (Value::Map(a), Value::Map(b)) => a == b,
+            (
+                Value::Struct {
+                    entity: a,
+                    fields: a_fields,
+                },
+                Value::Struct {
+                    entity: b,
+                    fields: b_fields,
+                },
+            ) => a.identifier() == b.identifier() && fields_eq(a_fields, b_fields),
+            (
+                Value::Enum {
+                    entity: a,
+                    variant: a_variant,
+                    payload: a_payload,
+                },
+                Value::Enum {
+                    entity: b,
+                    variant: b_variant,
+                    payload: b_payload,
+                },
+            ) => {
+                a.identifier() == b.identifier()
+                    && a_variant.identifier() == b_variant.identifier()
+                    && fields_eq(a_payload, b_payload)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl IntrospectValue for () {
+    fn introspect_value(&self) -> Value {
+        Value::Unit
+    }
+}
+
+impl IntrospectValue for bool {
+    fn introspect_value(&self) -> Value {
+        Value::Bool(*self)
+    }
+}
+
+impl IntrospectValue for char {
+    fn introspect_value(&self) -> Value {
+        Value::Char(*self)
+    }
+}
+
+macro_rules! introspect_value {
+    ($variant:ident($ty:ty): $($primitive:ty),*) => {
+        $(
+            impl IntrospectValue for $primitive {
+                fn introspect_value(&self) -> Value {
+                    Value::$variant(*self as $ty)
+                }
+            }
+        )*
+    };
+}
+
+introspect_value!(Int(i128): i8, i16, i32, i64, i128, isize);
+introspect_value!(UInt(u128): u8, u16, u32, u64, u128, usize);
+introspect_value!(Float(f64): f32, f64);
+
+impl IntrospectValue for str {
+    fn introspect_value(&self) -> Value {
+        Value::String(self.to_owned())
+    }
+}
+
+impl IntrospectValue for String {
+    fn introspect_value(&self) -> Value {
+        Value::String(self.clone())
+    }
+}
+
+impl<T: IntrospectValue + ?Sized> IntrospectValue for &T {
+    fn introspect_value(&self) -> Value {
+        (**self).introspect_value()
+    }
+}
+
+impl<T: IntrospectValue + ?Sized> IntrospectValue for Box<T> {
+    fn introspect_value(&self) -> Value {
+        (**self).introspect_value()
+    }
+}
+
+impl<T: IntrospectValue> IntrospectValue for Option<T> {
+    fn introspect_value(&self) -> Value {
+        Value::Option(
+            self.as_ref()
+                .map(|value| Box::new(value.introspect_value())),
+        )
+    }
+}
+
+impl<T: IntrospectValue> IntrospectValue for [T] {
+    fn introspect_value(&self) -> Value {
+        Value::Seq(self.iter().map(IntrospectValue::introspect_value).collect())
+    }
+}
+
+impl<T: IntrospectValue, const N: usize> IntrospectValue for [T; N] {
+    fn introspect_value(&self) -> Value {
+        self.as_slice().introspect_value()
+    }
+}
+
+impl<T: IntrospectValue> IntrospectValue for Vec<T> {
+    fn introspect_value(&self) -> Value {
+        self.as_slice().introspect_value()
+    }
+}
+
+impl<K: IntrospectValue, V: IntrospectValue> IntrospectValue for BTreeMap<K, V> {
+    fn introspect_value(&self) -> Value {
+        Value::Map(
+            self.iter()
+                .map(|(key, value)| (key.introspect_value(), value.introspect_value()))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: IntrospectValue, V: IntrospectValue, S> IntrospectValue
+    for std::collections::HashMap<K, V, S>
+{
+    fn introspect_value(&self) -> Value {
+        Value::Map(
+            self.iter()
+                .map(|(key, value)| (key.introspect_value(), value.introspect_value()))
+                .collect(),
+        )
+    }
+}
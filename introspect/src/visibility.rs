@@ -0,0 +1,3 @@
+//! Rust visibility.
+
+pub use introspect_core::visibility::Visibility;
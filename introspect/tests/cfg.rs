@@ -0,0 +1,213 @@
+//! Ensures that the introspected members and documentation match what is actually
+//! compiled when using `#[cfg(..)]` and `#[cfg_attr(..)]`.
+//!
+//! These tests are run with the `alloc` feature both enabled and disabled.
+
+#![allow(dead_code)]
+
+use introspect::Entity;
+use introspect::Introspect;
+use introspect::IntrospectedEntity;
+use introspect::IntrospectedEnum;
+use introspect::IntrospectedMembers;
+use introspect::Member;
+
+/// A configuration.
+#[cfg_attr(feature = "alloc", doc = "Allocates.")]
+#[cfg_attr(not(feature = "alloc"), doc = "Does not allocate.")]
+#[derive(Introspect)]
+struct Config {
+    /// Always compiled.
+    always: u8,
+
+    /// Only compiled with the `alloc` feature.
+    #[cfg(feature = "alloc")]
+    enabled: u8,
+
+    /// Only compiled without the `alloc` feature.
+    #[cfg(not(feature = "alloc"))]
+    disabled: u8,
+}
+
+#[cfg_attr(feature = "alloc", doc = "Only documented with the `alloc` feature.")]
+#[derive(Introspect)]
+enum Mode {
+    #[cfg_attr(all(), doc = "Always documented.")]
+    #[cfg_attr(any(), doc = "Never documented.")]
+    Always,
+
+    #[cfg(feature = "alloc")]
+    Enabled,
+
+    Tuple(
+        #[cfg_attr(feature = "alloc", doc = "Only documented with the `alloc` feature.")] u8,
+        #[cfg(feature = "alloc")] u16,
+    ),
+}
+
+#[derive(Introspect)]
+union Bits {
+    /// Always compiled.
+    always: u32,
+
+    /// Only compiled with the `alloc` feature.
+    #[cfg(feature = "alloc")]
+    enabled: f32,
+}
+
+fn documentation<T: IntrospectedEntity>() -> Option<&'static str> {
+    match T::introspected_entity() {
+        Entity::Enum(enum_) => enum_.documentation(),
+        Entity::Struct(struct_) => struct_.documentation(),
+        Entity::Union(union_) => union_.documentation(),
+    }
+}
+
+fn members<T: IntrospectedMembers>() -> Vec<(&'static str, Option<&'static str>)> {
+    T::introspected_members()
+        .map(|member| match member {
+            Member::Field(field) => (
+                field.identifier().unwrap_or_default(),
+                field.documentation(),
+            ),
+            Member::Variant(variant) => (variant.identifier(), variant.documentation()),
+        })
+        .collect()
+}
+
+/// Ensures that the fields or variants owned by the entity are the members.
+fn entity_owns_members<T: IntrospectedEntity + IntrospectedMembers>() {
+    let owned: Vec<_> = match T::introspected_entity() {
+        Entity::Enum(enum_) => enum_
+            .variants()
+            .iter()
+            .map(|variant| variant.identifier())
+            .collect(),
+        Entity::Struct(struct_) => struct_
+            .fields()
+            .iter()
+            .map(|field| field.identifier().unwrap_or_default())
+            .collect(),
+        Entity::Union(union_) => union_
+            .fields()
+            .iter()
+            .map(|field| field.identifier().unwrap_or_default())
+            .collect(),
+    };
+
+    assert_eq!(
+        owned,
+        members::<T>()
+            .into_iter()
+            .map(|(identifier, _)| identifier)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn feature_is_enabled() {
+    entity_owns_members::<Config>();
+    entity_owns_members::<Mode>();
+    entity_owns_members::<Bits>();
+
+    assert_eq!(
+        documentation::<Config>(),
+        Some("A configuration.\nAllocates.")
+    );
+    assert_eq!(
+        members::<Config>(),
+        vec![
+            ("always", Some("Always compiled.")),
+            ("enabled", Some("Only compiled with the `alloc` feature.")),
+        ]
+    );
+    assert_eq!(
+        members::<Bits>(),
+        vec![
+            ("always", Some("Always compiled.")),
+            ("enabled", Some("Only compiled with the `alloc` feature.")),
+        ]
+    );
+
+    assert_eq!(
+        documentation::<Mode>(),
+        Some("Only documented with the `alloc` feature.")
+    );
+    assert_eq!(
+        members::<Mode>(),
+        vec![
+            ("Always", Some("Always documented.")),
+            ("Enabled", None),
+            ("Tuple", None),
+        ]
+    );
+
+    assert_eq!(Mode::Tuple(0, 0).variant_index(), Some(2));
+    assert_eq!(
+        Mode::Tuple(0, 0)
+            .introspected_variant()
+            .map(|variant| variant.identifier()),
+        Some("Tuple")
+    );
+
+    match Mode::introspected_members().collect::<Vec<_>>()[..] {
+        [_, _, Member::Variant(tuple)] => {
+            assert_eq!(tuple.fields().len(), 2);
+            assert_eq!(
+                tuple.fields()[0].documentation(),
+                Some("Only documented with the `alloc` feature.")
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[cfg(not(feature = "alloc"))]
+#[test]
+fn feature_is_disabled() {
+    entity_owns_members::<Config>();
+    entity_owns_members::<Mode>();
+    entity_owns_members::<Bits>();
+
+    assert_eq!(
+        documentation::<Config>(),
+        Some("A configuration.\nDoes not allocate.")
+    );
+    assert_eq!(
+        members::<Config>(),
+        vec![
+            ("always", Some("Always compiled.")),
+            (
+                "disabled",
+                Some("Only compiled without the `alloc` feature.")
+            ),
+        ]
+    );
+    assert_eq!(
+        members::<Bits>(),
+        vec![("always", Some("Always compiled."))]
+    );
+
+    assert_eq!(documentation::<Mode>(), None);
+    assert_eq!(
+        members::<Mode>(),
+        vec![("Always", Some("Always documented.")), ("Tuple", None)]
+    );
+
+    assert_eq!(Mode::Tuple(0).variant_index(), Some(1));
+    assert_eq!(
+        Mode::Tuple(0)
+            .introspected_variant()
+            .map(|variant| variant.identifier()),
+        Some("Tuple")
+    );
+
+    match Mode::introspected_members().collect::<Vec<_>>()[..] {
+        [_, Member::Variant(tuple)] => {
+            assert_eq!(tuple.fields().len(), 1);
+            assert_eq!(tuple.fields()[0].documentation(), None);
+        }
+        _ => unreachable!(),
+    }
+}
@@ -0,0 +1,402 @@
+//! Ensures that the `Introspect` derive macro captures entities and their members
+//! as they are written in the source code.
+
+#![allow(dead_code)]
+
+use introspect::generics::GenericParam;
+use introspect::r#enum::VariantKind;
+use introspect::r#struct::StructKind;
+use introspect::r#type::Kind;
+use introspect::Introspect;
+use introspect::IntrospectedEnum;
+use introspect::IntrospectedStruct;
+use introspect::IntrospectedUnion;
+use introspect::Visibility;
+
+/// A configuration.
+#[derive(Introspect)]
+#[introspect(rename = "configuration", meta(version = 2))]
+pub struct Config<'a, T: Clone = u8, const N: usize = 4>
+where
+    T: Default,
+{
+    /// The name.
+    pub name: &'a str,
+
+    /// The ports.
+    #[introspect(rename = "listen_ports", hidden)]
+    pub(crate) ports: [u16; N],
+
+    #[introspect(meta(unit = "seconds"))]
+    timeout: Option<T>,
+
+    #[introspect(skip)]
+    skipped: (),
+}
+
+#[derive(Introspect)]
+struct Point(pub i32, (i32, i32));
+
+#[derive(Introspect)]
+struct Marker;
+
+/// A mode.
+#[derive(Introspect)]
+#[repr(u8)]
+enum Mode {
+    /// Idle.
+    Idle = 1,
+
+    Running,
+
+    #[introspect(hidden)]
+    Stopped = 10,
+}
+
+#[derive(Introspect)]
+#[repr(u128)]
+enum Wide {
+    Small = 1,
+    Large = u128::MAX,
+}
+
+#[derive(Introspect)]
+enum Shape {
+    Circle(f32),
+    Rectangle { width: f32, height: f32 },
+}
+
+/// Bits.
+#[derive(Introspect)]
+union Bits {
+    /// As an integer.
+    integer: u32,
+    float: f32,
+}
+
+#[test]
+fn structs_are_captured() {
+    let config = Config::<u8>::introspected_struct();
+
+    assert_eq!(config.identifier(), "Config");
+    assert_eq!(config.name(), "configuration");
+    assert_eq!(config.kind(), StructKind::Named);
+    assert_eq!(config.visibility(), &Visibility::Public);
+    assert_eq!(config.documentation(), Some("A configuration."));
+    assert_eq!(
+        config
+            .metadata()
+            .get("version")
+            .and_then(|value| value.as_int()),
+        Some(2)
+    );
+
+    let params = config
+        .generics()
+        .params()
+        .iter()
+        .map(|param| match param {
+            GenericParam::Lifetime { identifier, .. } => identifier.as_ref().to_owned(),
+            GenericParam::Type {
+                identifier,
+                bounds,
+                default,
+            } => format!(
+                "{}: {} = {}",
+                identifier.as_ref(),
+                bounds
+                    .iter()
+                    .map(AsRef::as_ref)
+                    .collect::<Vec<&str>>()
+                    .join(" + "),
+                default.as_ref().unwrap().text()
+            ),
+            GenericParam::Const {
+                identifier,
+                ty,
+                default,
+            } => format!(
+                "const {}: {} = {}",
+                identifier.as_ref(),
+                ty.text(),
+                default.as_deref().unwrap()
+            ),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(params, ["'a", "T: Clone = u8", "const N: usize = 4"]);
+
+    let predicates = config.generics().where_predicates();
+    assert_eq!(predicates.len(), 1);
+    assert_eq!(predicates[0].bounded(), "T");
+    assert_eq!(predicates[0].bounds(), ["Default"]);
+
+    let location = config.location().unwrap();
+    assert_eq!(location.file(), file!());
+    assert_eq!(location.module_path(), module_path!());
+    assert!(location.line() > 0);
+}
+
+#[test]
+fn fields_are_captured() {
+    let fields = Config::<u8>::introspected_fields();
+
+    let summary = fields
+        .iter()
+        .map(|field| {
+            (
+                field.index(),
+                field.identifier().unwrap(),
+                field.name().unwrap(),
+                field.ty().text(),
+                field.visibility().clone(),
+                field.is_hidden(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        summary,
+        [
+            (0, "name", "name", "&'a str", Visibility::Public, false),
+            (
+                1,
+                "ports",
+                "listen_ports",
+                "[u16; N]",
+                Visibility::Crate,
+                true
+            ),
+            (
+                2,
+                "timeout",
+                "timeout",
+                "Option<T>",
+                Visibility::Private,
+                false
+            ),
+        ]
+    );
+
+    assert_eq!(fields[0].documentation(), Some("The name."));
+    assert_eq!(fields[2].documentation(), None);
+    assert_eq!(
+        fields[2]
+            .metadata()
+            .get("unit")
+            .and_then(|value| value.as_str()),
+        Some("seconds")
+    );
+
+    assert!(
+        matches!(fields[0].ty().kind(), Kind::Reference { lifetime: Some(lifetime), mutable: false, .. } if lifetime == "'a")
+    );
+    assert!(matches!(fields[1].ty().kind(), Kind::Array { length, .. } if length == "N"));
+
+    let lines = fields
+        .iter()
+        .map(|field| field.location().unwrap().line())
+        .collect::<Vec<_>>();
+    assert!(lines.windows(2).all(|lines| lines[0] < lines[1]));
+}
+
+#[test]
+fn tuple_and_unit_structs_are_captured() {
+    let point = Point::introspected_struct();
+    assert_eq!(point.kind(), StructKind::Tuple);
+
+    let fields = Point::introspected_fields();
+    assert_eq!(fields.len(), 2);
+    assert_eq!(fields[0].identifier(), None);
+    assert_eq!(fields[0].visibility(), &Visibility::Public);
+    assert!(matches!(fields[1].ty().kind(), Kind::Tuple(elems) if elems.len() == 2));
+
+    assert_eq!(Marker::introspected_struct().kind(), StructKind::Unit);
+    assert!(Marker::introspected_fields().is_empty());
+}
+
+#[test]
+fn enums_are_captured() {
+    let mode = Mode::introspected_enum();
+
+    assert_eq!(mode.identifier(), "Mode");
+    assert_eq!(mode.repr(), Some("u8"));
+    assert_eq!(mode.documentation(), Some("A mode."));
+
+    let variants = Mode::introspected_variants()
+        .iter()
+        .map(|variant| {
+            (
+                variant.identifier(),
+                variant.kind(),
+                variant.discriminant(),
+                variant.discriminant_value(),
+                variant.is_hidden(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        variants,
+        [
+            ("Idle", VariantKind::Unit, Some("1"), Some(1), false),
+            ("Running", VariantKind::Unit, None, Some(2), false),
+            ("Stopped", VariantKind::Unit, Some("10"), Some(10), true),
+        ]
+    );
+
+    assert_eq!(
+        Mode::Running.introspected_variant().unwrap().identifier(),
+        "Running"
+    );
+}
+
+#[test]
+fn u128_enums_are_captured_without_discriminant_values() {
+    assert_eq!(Wide::introspected_enum().repr(), Some("u128"));
+    assert_eq!(
+        Wide::introspected_variants()
+            .iter()
+            .map(|variant| (variant.discriminant(), variant.discriminant_value()))
+            .collect::<Vec<_>>(),
+        [(Some("1"), None), (Some("u128::MAX"), None)]
+    );
+    assert_eq!(Wide::Large.variant_index(), Some(1));
+}
+
+#[test]
+fn variant_fields_are_captured() {
+    let variants = Shape::introspected_variants();
+
+    assert_eq!(variants[0].kind(), VariantKind::Tuple);
+    assert_eq!(variants[0].fields()[0].ty().text(), "f32");
+    assert_eq!(variants[0].discriminant_value(), None);
+
+    assert_eq!(variants[1].kind(), VariantKind::Named);
+    assert_eq!(
+        variants[1]
+            .fields()
+            .iter()
+            .map(|field| (field.index(), field.identifier().unwrap()))
+            .collect::<Vec<_>>(),
+        [(0, "width"), (1, "height")]
+    );
+
+    let shape = Shape::Rectangle {
+        width: 1.0,
+        height: 2.0,
+    };
+    assert_eq!(shape.variant_index(), Some(1));
+}
+
+#[test]
+fn variant_fields_are_located() {
+    let variants = Shape::introspected_variants();
+
+    let circle = variants[0].fields()[0].location().unwrap();
+    let circle_variant = variants[0].location().unwrap();
+    assert_eq!(circle.file(), file!());
+    assert_eq!(circle.module_path(), module_path!());
+    assert_eq!(circle.line(), circle_variant.line());
+    assert!(circle.column() > circle_variant.column());
+
+    let rectangle = variants[1]
+        .fields()
+        .iter()
+        .map(|field| field.location().unwrap())
+        .collect::<Vec<_>>();
+    let rectangle_variant = variants[1].location().unwrap();
+    assert!(rectangle
+        .iter()
+        .all(|location| location.line() == rectangle_variant.line()));
+    assert!(rectangle[0].column() > rectangle_variant.column());
+    assert!(rectangle[1].column() > rectangle[0].column());
+}
+
+#[test]
+fn unions_are_captured() {
+    let union_ = Bits::introspected_union();
+
+    assert_eq!(union_.identifier(), "Bits");
+    assert_eq!(union_.visibility(), &Visibility::Private);
+    assert_eq!(union_.documentation(), Some("Bits."));
+    assert!(union_.location().is_some());
+
+    let fields = Bits::introspected_fields()
+        .iter()
+        .map(|field| {
+            (
+                field.identifier().unwrap(),
+                field.ty().text(),
+                field.documentation(),
+                field.location().is_some(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        fields,
+        [
+            ("integer", "u32", Some("As an integer."), true),
+            ("float", "f32", None, true),
+        ]
+    );
+}
+
+#[cfg(feature = "attributes")]
+#[test]
+fn attributes_are_captured() {
+    use introspect::attribute::Meta;
+
+    #[derive(Introspect)]
+    #[must_use = "reason"]
+    struct Token {
+        field: u8,
+    }
+
+    let attributes = Token::introspected_struct().attributes();
+    assert_eq!(attributes.len(), 1);
+    assert_eq!(attributes[0].path(), "must_use");
+    assert!(matches!(attributes[0].meta(), Meta::NameValue { value } if value == "\"reason\""));
+}
+
+/// Ensures that every path emitted by the derive macro is fully qualified.
+#[no_implicit_prelude]
+mod no_implicit_prelude {
+    #[derive(::introspect::Introspect)]
+    pub struct Config<T> {
+        pub name: ::core::option::Option<T>,
+        #[introspect(rename = "other", meta(flag = true))]
+        pub other: u8,
+    }
+
+    #[derive(::introspect::Introspect)]
+    #[repr(i8)]
+    pub enum Mode {
+        Idle = -1,
+        Running,
+    }
+
+    #[derive(::introspect::Introspect)]
+    pub enum Shape {
+        Circle(f32),
+        Rectangle { width: f32 },
+    }
+
+    #[derive(::introspect::Introspect)]
+    pub union Bits {
+        pub integer: u32,
+    }
+}
+
+#[test]
+fn derives_within_a_module_without_a_prelude() {
+    use no_implicit_prelude::*;
+
+    assert_eq!(Config::<u8>::introspected_fields()[1].name(), Some("other"));
+    assert_eq!(
+        Mode::introspected_variants()
+            .iter()
+            .map(|variant| variant.discriminant_value())
+            .collect::<Vec<_>>(),
+        [Some(-1), Some(0)]
+    );
+    assert_eq!(Shape::introspected_variants()[1].fields().len(), 1);
+    assert_eq!(Bits::introspected_union().identifier(), "Bits");
+}
@@ -0,0 +1,359 @@
+//! Ensures that documentation round-trips through the `Introspect` derive macro
+//! exactly, including adversarial strings (within documentation, renames, and
+//! metadata), markdown formatting, and documentation that is provided via macro
+//! calls.
+
+#![allow(dead_code)]
+
+use introspect::Entity;
+use introspect::Introspect;
+use introspect::IntrospectedEntity;
+use introspect::IntrospectedMembers;
+use introspect::Member;
+
+/// He said "hi"# and then "bye"##.
+#[derive(Introspect)]
+struct Quotes {
+    /// A raw string looks like r#"this"# or r##"this"##.
+    raw: u8,
+
+    /// "#
+    unterminated: u8,
+}
+
+#[doc = "A backslash \\ and an escaped newline \\n that is not a newline."]
+#[derive(Introspect)]
+enum Escapes {
+    #[doc = "A tab\tand a\nnewline."]
+    Whitespace,
+
+    #[doc = "Braces { } and brackets [ ] and a hash # with no quote."]
+    Delimiters,
+}
+
+#[doc = "Unicode: ünïcødé, 🦀, and a zero width space (\u{200b})."]
+#[derive(Introspect)]
+struct Unicode {
+    /// 日本語のドキュメント。
+    #[introspect(rename = "\"#名前\"#")]
+    #[introspect(meta(label = "r#\"°C\"#\\"))]
+    field: u8,
+}
+
+fn documentation<T: IntrospectedEntity>() -> Option<&'static str> {
+    match T::introspected_entity() {
+        Entity::Enum(enum_) => enum_.documentation(),
+        Entity::Struct(struct_) => struct_.documentation(),
+        Entity::Union(union_) => union_.documentation(),
+    }
+}
+
+fn member_documentation<T: IntrospectedMembers>() -> Vec<Option<&'static str>> {
+    T::introspected_members()
+        .map(|member| match member {
+            Member::Field(field) => field.documentation(),
+            Member::Variant(variant) => variant.documentation(),
+        })
+        .collect()
+}
+
+#[test]
+fn quotes_and_hashes_round_trip() {
+    assert_eq!(
+        documentation::<Quotes>(),
+        Some("He said \"hi\"# and then \"bye\"##.")
+    );
+
+    assert_eq!(
+        member_documentation::<Quotes>(),
+        vec![
+            Some("A raw string looks like r#\"this\"# or r##\"this\"##."),
+            Some("\"#"),
+        ]
+    );
+}
+
+#[test]
+fn escapes_round_trip() {
+    assert_eq!(
+        documentation::<Escapes>(),
+        Some("A backslash \\ and an escaped newline \\n that is not a newline.")
+    );
+
+    assert_eq!(
+        member_documentation::<Escapes>(),
+        vec![
+            Some("A tab\tand a\nnewline."),
+            Some("Braces { } and brackets [ ] and a hash # with no quote."),
+        ]
+    );
+}
+
+#[test]
+fn unicode_round_trips() {
+    assert_eq!(
+        documentation::<Unicode>(),
+        Some("Unicode: ünïcødé, 🦀, and a zero width space (\u{200b}).")
+    );
+
+    let field = match Unicode::introspected_members().collect::<Vec<_>>()[..] {
+        [Member::Field(field)] => field,
+        _ => unreachable!(),
+    };
+
+    assert_eq!(field.documentation(), Some("日本語のドキュメント。"));
+    assert_eq!(field.name(), Some("\"#名前\"#"));
+    assert_eq!(
+        field
+            .metadata()
+            .get("label")
+            .and_then(|value| value.as_str()),
+        Some("r#\"°C\"#\\")
+    );
+}
+
+/// A configuration.
+#[doc = include_str!("docs/config.md")]
+#[derive(Introspect)]
+struct Config {
+    #[doc = include_str!("docs/port.md")]
+    port: u16,
+
+    /// The host.
+    host: u8,
+}
+
+#[doc = concat!("The ", "mode.")]
+#[derive(Introspect)]
+enum Mode {
+    #[doc = concat!("Fast", " ", "mode.")]
+    /// Use with care.
+    Fast {
+        #[doc = stringify!(speed)]
+        speed: u8,
+    },
+}
+
+#[doc = env!("CARGO_PKG_NAME")]
+#[derive(Introspect)]
+union Bits {
+    #[doc = concat!("An ", "integer.")]
+    int: u32,
+}
+
+#[test]
+fn macro_calls_are_evaluated_within_the_deriving_crate() {
+    assert_eq!(
+        documentation::<Config>(),
+        Some(
+            "A configuration.\n# Configuration\n\nThe configuration is loaded from `config.toml`.\n"
+        )
+    );
+    assert_eq!(
+        member_documentation::<Config>(),
+        vec![Some("The port to listen on."), Some("The host.")]
+    );
+
+    assert_eq!(documentation::<Mode>(), Some("The mode."));
+    assert_eq!(
+        member_documentation::<Mode>(),
+        vec![Some("Fast mode.\nUse with care.")]
+    );
+
+    match Mode::introspected_members().collect::<Vec<_>>()[..] {
+        [Member::Variant(variant)] => {
+            assert_eq!(variant.fields()[0].documentation(), Some("speed"));
+        }
+        _ => unreachable!(),
+    }
+
+    assert_eq!(documentation::<Bits>(), Some("introspect"));
+    assert_eq!(member_documentation::<Bits>(), vec![Some("An integer.")]);
+}
+
+/// A list:
+///
+/// * One.
+///   * Nested.
+///
+/// ```
+/// let indented = true;
+/// ```
+///
+///     let indented = true;
+#[doc = "Mixed with an attribute."]
+#[derive(Introspect)]
+struct Formatting {
+    /**
+     * A block comment:
+     *
+     * * One.
+     *   * Nested.
+     */
+    block: u8,
+
+    /** A single line block comment. */
+    line: u8,
+
+    #[doc = include_str!("docs/port.md")]
+    ///   Indented.
+    included: u8,
+}
+
+fn member_documentation_raw<T: IntrospectedMembers>() -> Vec<Option<&'static str>> {
+    T::introspected_members()
+        .map(|member| match member {
+            Member::Field(field) => field.documentation_raw(),
+            Member::Variant(variant) => variant.documentation_raw(),
+        })
+        .collect()
+}
+
+#[test]
+fn formatting_is_preserved() {
+    assert_eq!(
+        documentation::<Formatting>(),
+        Some(concat!(
+            "A list:\n",
+            "\n",
+            "* One.\n",
+            "  * Nested.\n",
+            "\n",
+            "```\n",
+            "let indented = true;\n",
+            "```\n",
+            "\n",
+            "    let indented = true;\n",
+            "Mixed with an attribute.",
+        ))
+    );
+
+    assert_eq!(
+        member_documentation::<Formatting>(),
+        vec![
+            Some("A block comment:\n\n* One.\n  * Nested."),
+            Some("A single line block comment."),
+            Some("The port to listen on.\n  Indented."),
+        ]
+    );
+
+    match Formatting::introspected_entity() {
+        Entity::Struct(struct_) => assert_eq!(
+            struct_.documentation_raw(),
+            Some(concat!(
+                " A list:\n",
+                "\n",
+                " * One.\n",
+                "   * Nested.\n",
+                "\n",
+                " ```\n",
+                " let indented = true;\n",
+                " ```\n",
+                "\n",
+                "     let indented = true;\n",
+                "Mixed with an attribute.",
+            ))
+        ),
+        _ => unreachable!(),
+    }
+
+    assert_eq!(
+        member_documentation_raw::<Formatting>(),
+        vec![
+            Some("\n     * A block comment:\n     *\n     * * One.\n     *   * Nested.\n     "),
+            Some(" A single line block comment. "),
+            Some("The port to listen on.\n   Indented."),
+        ]
+    );
+}
+
+///foo
+#[doc = "bar"]
+#[derive(Introspect)]
+struct Unindented {
+    #[doc = "* One.\n* Two."]
+    list: u8,
+
+    #[doc = "\n    A list:\n\n    * One.\n"]
+    padded: u8,
+}
+
+#[test]
+fn unindented_doc_comments_mix_with_attributes() {
+    assert_eq!(documentation::<Unindented>(), Some("foo\nbar"));
+}
+
+#[test]
+fn attributes_are_not_undecorated() {
+    assert_eq!(
+        member_documentation::<Unindented>(),
+        vec![Some("* One.\n* Two."), Some("\nA list:\n\n* One.\n")]
+    );
+}
+
+/// Starts the server.
+///
+/// The server listens on the configured port.
+///
+/// # Errors
+///
+/// Fails if the port is in use.
+///
+/// # Examples
+///
+/// ```
+/// # use server::Server;
+/// let server = Server::default();
+/// ```
+#[derive(Introspect)]
+struct Server {
+    /// The port.
+    port: u16,
+}
+
+#[test]
+fn documentation_is_structured() {
+    let documentation = match Server::introspected_entity() {
+        Entity::Struct(struct_) => struct_.documentation_parsed().unwrap(),
+        _ => unreachable!(),
+    };
+
+    assert_eq!(documentation.summary(), "Starts the server.");
+    assert_eq!(
+        documentation.body(),
+        "The server listens on the configured port."
+    );
+
+    let sections = documentation
+        .sections()
+        .map(|section| (section.name(), section.content()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        sections,
+        vec![
+            ("Errors", "Fails if the port is in use."),
+            (
+                "Examples",
+                "```\n# use server::Server;\nlet server = Server::default();\n```"
+            ),
+        ]
+    );
+
+    let examples = documentation.section("Examples").unwrap();
+    assert_eq!(
+        examples
+            .code_blocks()
+            .map(|block| block.code())
+            .collect::<Vec<_>>(),
+        vec!["# use server::Server;\nlet server = Server::default();"]
+    );
+
+    match Server::introspected_members().collect::<Vec<_>>()[..] {
+        [Member::Field(field)] => {
+            let documentation = field.documentation_parsed().unwrap();
+            assert_eq!(documentation.summary(), "The port.");
+            assert!(documentation.sections().next().is_none());
+        }
+        _ => unreachable!(),
+    }
+}
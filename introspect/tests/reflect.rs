@@ -0,0 +1,105 @@
+//! Ensures that the fields of structs that opt in via `#[introspect(reflect)]` can
+//! be accessed dynamically by identifier.
+//!
+//! These tests are run with the `alloc` feature both enabled and disabled.
+
+#![allow(dead_code)]
+
+use introspect::Introspect;
+use introspect::Reflect;
+
+#[derive(Introspect)]
+#[introspect(reflect)]
+struct Config<T> {
+    /// The port.
+    #[introspect(rename = "listen_port")]
+    port: u16,
+
+    /// The type of the configuration.
+    r#type: T,
+
+    /// Never reflected.
+    #[introspect(skip)]
+    skipped: u8,
+}
+
+#[derive(Introspect)]
+#[introspect(reflect)]
+struct Point(i32, #[introspect(skip)] i32, i32);
+
+#[test]
+fn fields_are_accessed_by_identifier() {
+    let mut config = Config {
+        port: 80,
+        r#type: "server",
+        skipped: 0,
+    };
+
+    assert_eq!(
+        config
+            .get("port")
+            .and_then(|port| port.downcast_ref::<u16>()),
+        Some(&80)
+    );
+    assert_eq!(
+        config
+            .get("r#type")
+            .and_then(|ty| ty.downcast_ref::<&str>()),
+        Some(&"server")
+    );
+    assert!(config.get("listen_port").is_none());
+    assert!(config.get("skipped").is_none());
+
+    *config
+        .get_mut("port")
+        .and_then(|port| port.downcast_mut::<u16>())
+        .unwrap() = 8080;
+    assert_eq!(config.port, 8080);
+
+    let fields = config
+        .reflected_fields()
+        .map(|(field, _)| field.identifier().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(fields, ["port", "r#type"]);
+}
+
+#[test]
+fn tuple_fields_are_accessed_by_index() {
+    let point = Point(1, 2, 3);
+
+    assert_eq!(
+        point.get("0").and_then(|x| x.downcast_ref::<i32>()),
+        Some(&1)
+    );
+    assert!(point.get("1").is_none());
+    assert_eq!(
+        point.get("2").and_then(|z| z.downcast_ref::<i32>()),
+        Some(&3)
+    );
+
+    let fields = point
+        .reflected_fields()
+        .map(|(field, value)| (field.index(), *value.downcast_ref::<i32>().unwrap()))
+        .collect::<Vec<_>>();
+    assert_eq!(fields, [(0, 1), (2, 3)]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn fields_are_set_by_identifier() {
+    use introspect::reflect::Error;
+
+    let mut point = Point(1, 2, 3);
+
+    point.set("2", Box::new(4i32)).unwrap();
+    assert_eq!(point.2, 4);
+
+    assert!(matches!(
+        point.set("0", Box::new(4u8)),
+        Err(Error::MismatchedType { expected: "i32" })
+    ));
+    assert!(matches!(
+        point.set("1", Box::new(4i32)),
+        Err(Error::UnknownField)
+    ));
+}
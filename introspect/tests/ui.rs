@@ -0,0 +1,8 @@
+//! Ensures that invalid uses of the `Introspect` derive macro fail to compile with
+//! errors that point at the offending tokens.
+
+#[test]
+fn ui() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,11 @@
+use introspect::Introspect;
+
+#[derive(Introspect)]
+#[introspect(rename = "first")]
+#[introspect(rename = "second")]
+enum Mode {
+    Fast,
+    Slow,
+}
+
+fn main() {}
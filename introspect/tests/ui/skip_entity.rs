@@ -0,0 +1,9 @@
+use introspect::Introspect;
+
+#[derive(Introspect)]
+#[introspect(skip)]
+struct Config {
+    port: u16,
+}
+
+fn main() {}
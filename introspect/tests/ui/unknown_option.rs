@@ -0,0 +1,9 @@
+use introspect::Introspect;
+
+#[derive(Introspect)]
+struct Config {
+    #[introspect(unknown)]
+    port: u16,
+}
+
+fn main() {}
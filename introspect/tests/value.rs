@@ -0,0 +1,124 @@
+//! Ensures that instances of entities that opt in via `#[introspect(value)]` are
+//! converted into self-describing values.
+
+#![cfg(feature = "alloc")]
+#![allow(dead_code)]
+
+use std::collections::BTreeMap;
+
+use introspect::value::Value;
+use introspect::Introspect;
+use introspect::IntrospectValue;
+
+#[derive(Introspect)]
+#[introspect(value)]
+struct Config<T> {
+    /// The name.
+    name: String,
+
+    /// The ports.
+    ports: Vec<u16>,
+
+    /// The timeout.
+    timeout: Option<T>,
+
+    /// The labels.
+    labels: BTreeMap<String, i8>,
+
+    /// The mode.
+    mode: Mode,
+
+    /// Never converted.
+    #[introspect(skip)]
+    skipped: std::time::Duration,
+}
+
+#[derive(Introspect)]
+#[introspect(value)]
+enum Mode {
+    Unit,
+    Tuple(u8, #[introspect(skip)] std::time::Duration, char),
+    Named {
+        scale: f32,
+    },
+    #[introspect(skip)]
+    Skipped,
+}
+
+#[derive(Introspect)]
+#[introspect(value)]
+enum Never {}
+
+#[test]
+fn structs_are_converted() {
+    let config = Config {
+        name: String::from("server"),
+        ports: vec![80, 443],
+        timeout: Some(30u64),
+        labels: BTreeMap::from([(String::from("tier"), -1)]),
+        mode: Mode::Unit,
+        skipped: std::time::Duration::ZERO,
+    };
+
+    let (entity, fields) = match config.introspect_value() {
+        Value::Struct { entity, fields } => (entity, fields),
+        _ => unreachable!(),
+    };
+
+    assert_eq!(entity.identifier(), "Config");
+    assert_eq!(
+        fields
+            .iter()
+            .map(|(field, _)| field.identifier().unwrap())
+            .collect::<Vec<_>>(),
+        ["name", "ports", "timeout", "labels", "mode"]
+    );
+    assert_eq!(fields[0].1, Value::String(String::from("server")));
+    assert_eq!(
+        fields[1].1,
+        Value::Seq(vec![Value::UInt(80), Value::UInt(443)])
+    );
+    assert_eq!(fields[2].1, Value::Option(Some(Box::new(Value::UInt(30)))));
+    assert_eq!(
+        fields[3].1,
+        Value::Map(vec![(Value::String(String::from("tier")), Value::Int(-1))])
+    );
+    assert_eq!(fields[4].1, Mode::Unit.introspect_value());
+}
+
+#[test]
+fn enums_are_converted() {
+    let payload = |mode: Mode| match mode.introspect_value() {
+        Value::Enum {
+            entity,
+            variant,
+            payload,
+        } => {
+            assert_eq!(entity.identifier(), "Mode");
+            (
+                variant.identifier(),
+                payload
+                    .into_iter()
+                    .map(|(field, value)| (field.index(), value))
+                    .collect::<Vec<_>>(),
+            )
+        }
+        _ => unreachable!(),
+    };
+
+    assert_eq!(payload(Mode::Unit), ("Unit", vec![]));
+    assert_eq!(
+        payload(Mode::Tuple(1, std::time::Duration::ZERO, 'x')),
+        ("Tuple", vec![(0, Value::UInt(1)), (2, Value::Char('x'))])
+    );
+    assert_eq!(
+        payload(Mode::Named { scale: 0.5 }),
+        ("Named", vec![(0, Value::Float(0.5))])
+    );
+
+    assert_eq!(Mode::Skipped.introspect_value(), Value::Skipped);
+    assert_ne!(
+        Mode::Unit.introspect_value(),
+        Mode::Skipped.introspect_value()
+    );
+}
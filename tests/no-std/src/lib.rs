@@ -0,0 +1,83 @@
+//! A `#![no_std]` crate that ensures `introspect` builds without `std` or an
+//! allocator.
+//!
+//! This crate is built for the `thumbv7em-none-eabi` target in CI.
+
+#![no_std]
+#![warn(missing_docs)]
+#![warn(rust_2018_idioms)]
+#![warn(rust_2021_compatibility)]
+#![warn(missing_debug_implementations)]
+#![warn(rustdoc::broken_intra_doc_links)]
+
+use introspect::Introspect;
+use introspect::IntrospectedStruct;
+
+/// A telemetry frame that is reported by the firmware.
+#[derive(Debug, Introspect)]
+pub struct Telemetry {
+    /// The temperature in millidegrees Celsius.
+    #[introspect(meta(unit = "m°C"))]
+    pub temperature: i32,
+
+    /// The supply voltage in millivolts.
+    #[introspect(meta(unit = "mV"))]
+    pub voltage: u16,
+
+    /// The operating mode.
+    pub mode: Mode,
+}
+
+/// The operating mode of the firmware.
+#[derive(Debug, Introspect)]
+pub enum Mode {
+    /// The firmware is waiting for a command.
+    Idle,
+
+    /// The firmware is sampling its sensors.
+    Sampling = 4,
+}
+
+/// Gets the documentation for a field of the [`Telemetry`] frame by name.
+pub fn documentation(name: &str) -> Option<&'static str> {
+    Telemetry::introspected_fields()
+        .iter()
+        .find(|field| field.name() == Some(name))
+        .and_then(|field| field.documentation())
+}
+
+/// Gets the unit of a field of the [`Telemetry`] frame by name.
+pub fn unit(name: &str) -> Option<&'static str> {
+    Telemetry::introspected_fields()
+        .iter()
+        .find(|field| field.name() == Some(name))
+        .and_then(|field| field.metadata().get("unit"))
+        .and_then(|value| value.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use introspect::IntrospectedEnum;
+
+    use super::*;
+
+    #[test]
+    fn telemetry_is_introspected_without_allocating() {
+        assert_eq!(
+            documentation("voltage"),
+            Some("The supply voltage in millivolts.")
+        );
+        assert_eq!(unit("temperature"), Some("m°C"));
+        assert_eq!(documentation("missing"), None);
+
+        match Mode::introspected_variants() {
+            [idle, sampling] => {
+                assert_eq!(idle.discriminant_value(), Some(0));
+                assert_eq!(sampling.discriminant_value(), Some(4));
+            }
+            _ => unreachable!(),
+        }
+
+        assert_eq!(Telemetry::introspected_struct().identifier(), "Telemetry");
+    }
+}